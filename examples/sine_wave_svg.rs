@@ -3,7 +3,10 @@
 
 use std::{fs::File, io::Write};
 
-use d3rs::{Document, DomainScale, Group, IterableScale, LengthOrPercentage, Linear, Result};
+use d3rs::{
+    ClipPath, Defs, Document, DomainScale, Group, IterableScale, Linear, NumberFormat, PathData,
+    Result,
+};
 
 fn main() -> Result<()> {
     const BORDER_WIDTH_LEFT: usize = 30;
@@ -13,13 +16,57 @@ fn main() -> Result<()> {
     const WAVE_WIDTH: usize = 2 * 720;
     const WAVE_HEIGHT: usize = HEIGHT - BORDER_HEIGHT * 2;
     const WIDTH: usize = WAVE_WIDTH + BORDER_WIDTH_LEFT + BORDER_WIDTH_RIGHT;
-    const PLOT_THICKNESS: usize = 2;
 
     const TWO_PI: f64 = 3.0 * std::f64::consts::PI;
 
-    let x_axis_radians = Linear::try_new(0.0_f64, TWO_PI, WAVE_WIDTH)?;
-    let x_axis_degrees = Linear::try_new(0, 540_u16, WAVE_WIDTH)?;
-    let y_axis = Linear::try_new(1.3_f64, -1.3, WAVE_HEIGHT)?;
+    const WAVE_LEFT: usize = BORDER_WIDTH_LEFT;
+    const WAVE_RIGHT: usize = WAVE_LEFT + WAVE_WIDTH - 1;
+    const WAVE_TOP: usize = BORDER_HEIGHT;
+    const WAVE_BOTTOM: usize = WAVE_TOP + WAVE_HEIGHT - 1;
+
+    let x_axis_radians =
+        Linear::try_new(0.0_f64, TWO_PI, WAVE_WIDTH)?.range(WAVE_LEFT, WAVE_RIGHT)?;
+    let x_axis_degrees = Linear::try_new(0, 540_u16, WAVE_WIDTH)?.range(WAVE_LEFT, WAVE_RIGHT)?;
+    let y_axis = Linear::try_new(-1.3_f64, 1.3, WAVE_HEIGHT)?.range(WAVE_BOTTOM, WAVE_TOP)?;
+    let y_label: NumberFormat = ">4.1f".parse().unwrap();
+
+    // the tangent leaves the domain near its asymptotes, where a new subpath starts
+    let (tangent, _) = x_axis_radians.iter().fold(
+        (PathData::new(), false),
+        |(path, drawing), (radians, coord_x)| match y_axis
+            .domain_to_float_coordinate(f64::tan(radians))
+        {
+            Some(coord_y) if drawing => (path.line_to(coord_x as f64, coord_y), true),
+            Some(coord_y) => (path.move_to(coord_x as f64, coord_y), true),
+            None => (path, false),
+        },
+    );
+    let sine: PathData = x_axis_radians
+        .iter()
+        .filter_map(|(radians, coord_x)| {
+            y_axis
+                .domain_to_float_coordinate(f64::sin(radians))
+                .map(|coord_y| (coord_x as f64, coord_y))
+        })
+        .collect();
+    let cosine: PathData = x_axis_radians
+        .iter()
+        .filter_map(|(radians, coord_x)| {
+            y_axis
+                .domain_to_float_coordinate(f64::cos(radians))
+                .map(|coord_y| (coord_x as f64, coord_y))
+        })
+        .collect();
+
+    // keep the curves from spilling over the axes
+    let mut wave_area = ClipPath::new("wave-area");
+    wave_area.add(d3rs::rect!(
+        (WAVE_LEFT, WAVE_TOP),
+        (WAVE_WIDTH, WAVE_HEIGHT)
+    ));
+    let wave_area_url = wave_area.url();
+    let mut defs = Defs::default();
+    defs.add(wave_area);
 
     let mut out = File::create("sine.svg").unwrap();
     write!(
@@ -29,58 +76,52 @@ fn main() -> Result<()> {
             WIDTH,
             HEIGHT,
             style:
-                format!(
-                    r#"
-            .tangent circle {{
-                fill: red;
-            }}
+                r#"
+            .chart path {
+                fill: none;
+                stroke-width: 2;
+            }
 
-            .sine circle {{
-                fill: green;
-            }}
+            .tangent {
+                stroke: red;
+            }
 
-            .cosine circle {{
-                fill: blue;
-            }}
+            .sine {
+                stroke: green;
+            }
 
-            .axis line {{
-                stroke: black;
-            }}
-
-            .horizontal-ticks, .chart {{
-                transform: translate( {transx}px, {transy}px );
-            }}
+            .cosine {
+                stroke: blue;
+            }
 
-            .vertical-ticks {{
-                transform: translate( 0, {transy}px );
-            }}
+            .axis line {
+                stroke: black;
+            }
 
-            .vertical-ticks text {{
+            .vertical-ticks text {
                 text-anchor: end;
-            }}
+            }
 
-            .horizontal-ticks text {{
+            .horizontal-ticks text {
                 text-anchor: middle;
-            }}
+            }
 
-            .tick-label {{
+            .tick-label {
                 font-size: 8pt;
-            }}
+            }
 
-            line.tick {{
+            line.tick {
                 stroke: grey;
-            }}
+            }
         "#,
-                    transx = BORDER_WIDTH_LEFT,
-                    transy = BORDER_HEIGHT
-                ),
             [
+                defs,
                 d3rs::group!( class: "axis", [
 
                     d3rs::horizontal_axis!(
                         ( 0, "50%" ), ( "100%", "50%" ),
                         x_axis_degrees.intervals(15),
-                        position: WAVE_HEIGHT / 2,
+                        position: WAVE_TOP + WAVE_HEIGHT / 2,
                         tick_label: |deg,_| if deg >0 {Some(deg)} else {None},
                         label_y_offset: 10
                     ),
@@ -91,7 +132,7 @@ fn main() -> Result<()> {
                         position: BORDER_WIDTH_LEFT,
                         tick_label: |y, _| {
                             if f64::round(f64::abs(y) * 11.0) > 0.0 {
-                                Some(format!("{:>4.1}", y))
+                                Some(y_label.format(y))
                             } else {
                                 None
                             }
@@ -100,43 +141,10 @@ fn main() -> Result<()> {
                         label_y_offset: 4
                     )
                 ]),
-                d3rs::group!( class: "chart", [
-                    d3rs::plot!(x_axis_radians.iter(), |radians, coord_x| {
-                        let tan_x = f64::tan(radians);
-                        let deg: f64 = (radians * 360.0) / TWO_PI;
-                        if let Some(coord_y) = y_axis.domain_to_coordinate(tan_x) {
-
-                        Some(d3rs::circle!(
-                            (coord_x, coord_y),
-                            PLOT_THICKNESS,
-                            meta: format!("angle={:08.3};tanx={:5.4}", deg, tan_x)
-                        ))
-                    } else {
-                        None
-                    }
-                    }, class: "tangent"),
-                    d3rs::plot!(x_axis_radians.iter(), |radians, coord_x| {
-                        let sin_x = f64::sin(radians);
-                        let deg: f64 = (radians * 360.0) / TWO_PI;
-                        let coord_y = y_axis.domain_to_coordinate(sin_x).unwrap() as usize;
-
-                        Some(d3rs::circle!(
-                            (coord_x, coord_y),
-                            PLOT_THICKNESS,
-                            meta: format!("angle={:08.3};sinx={:5.4}", deg, sin_x)
-                        ))
-                    }, class: "sine"),
-                    d3rs::plot!(x_axis_radians.iter(), |radians, coord_x| {
-                        let cos_x = f64::cos(radians);
-                        let deg: f64 = (radians * 360.0) / TWO_PI;
-                        let coord_y = y_axis.domain_to_coordinate(cos_x).unwrap() as usize;
-
-                        Some(d3rs::circle!(
-                            (coord_x, coord_y),
-                            PLOT_THICKNESS,
-                            meta: format!("angle={:08.3};cosx={:5.4}", deg, cos_x)
-                        ))
-                    }, class: "cosine")
+                d3rs::group!( class: "chart", clip_path: wave_area_url, [
+                    d3rs::path!(tangent, class: "tangent"),
+                    d3rs::path!(sine, class: "sine"),
+                    d3rs::path!(cosine, class: "cosine")
                 ])
             ]
         )
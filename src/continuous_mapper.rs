@@ -6,10 +6,13 @@ use std::{
     slice::Iter,
 };
 
+use crate::scales::Scale;
+
+/// A domain value and its coordinate, which is either a whole pixel or an exact [f64]
 #[derive(Debug, PartialEq)]
-pub struct ScaledStep<'ss, DOMAIN> {
+pub struct ScaledStep<'ss, DOMAIN, COORDINATE = usize> {
     value: &'ss DOMAIN,
-    dimension: usize,
+    dimension: COORDINATE,
 }
 
 pub struct ScaledSteps<DOMAIN = u16> {
@@ -17,14 +20,29 @@ pub struct ScaledSteps<DOMAIN = u16> {
     values: Vec<DOMAIN>,
     dimension_step: usize,
     dimension_start: usize,
+    exact_step: f64,
+    exact_start: f64,
 }
 
-pub struct ScaledStepsIter<'ssi, DOMAIN> {
-    dimension: usize,
-    dimension_step: usize,
+pub struct ScaledStepsIter<'ssi, DOMAIN, COORDINATE = usize> {
+    dimension: COORDINATE,
+    dimension_step: COORDINATE,
     iter: Iter<'ssi, DOMAIN>,
 }
 
+impl<'ss, DOMAIN, COORDINATE> ScaledStep<'ss, DOMAIN, COORDINATE>
+where
+    COORDINATE: Copy,
+{
+    pub fn value(&self) -> &'ss DOMAIN {
+        self.value
+    }
+
+    pub fn dimension(&self) -> COORDINATE {
+        self.dimension
+    }
+}
+
 impl<'ssi, DOMAIN> ScaledSteps<DOMAIN> {
     pub fn new(dimension: usize) -> Self {
         Self {
@@ -32,6 +50,8 @@ impl<'ssi, DOMAIN> ScaledSteps<DOMAIN> {
             values: Vec::new(),
             dimension_step: 1,
             dimension_start: 0,
+            exact_step: 1.0,
+            exact_start: 0.0,
         }
     }
 
@@ -42,6 +62,15 @@ impl<'ssi, DOMAIN> ScaledSteps<DOMAIN> {
             iter: self.values.iter(),
         }
     }
+
+    /// Iterate over the steps with their exact, sub-pixel, coordinates
+    pub fn float_iter(&'ssi self) -> ScaledStepsIter<'ssi, DOMAIN, f64> {
+        ScaledStepsIter {
+            dimension: self.exact_start,
+            dimension_step: self.exact_step,
+            iter: self.values.iter(),
+        }
+    }
 }
 
 impl<N> ScaledSteps<N>
@@ -58,6 +87,11 @@ where
             values
         });
 
+        if count > 0 {
+            self.exact_step = self.dimension as f64 / count as f64;
+            self.exact_start = 0.0;
+        }
+
         self
     }
 }
@@ -143,13 +177,18 @@ where
 
         self.dimension_step = self.dimension / (self.values.len() + 1);
         self.dimension_start = self.dimension_step;
+        self.exact_step = self.dimension as f64 / (self.values.len() + 1) as f64;
+        self.exact_start = self.exact_step;
 
         self
     }
 }
 
-impl<'ssi, DOMAIN> Iterator for ScaledStepsIter<'ssi, DOMAIN> {
-    type Item = ScaledStep<'ssi, DOMAIN>;
+impl<'ssi, DOMAIN, COORDINATE> Iterator for ScaledStepsIter<'ssi, DOMAIN, COORDINATE>
+where
+    COORDINATE: Copy + AddAssign<COORDINATE>,
+{
+    type Item = ScaledStep<'ssi, DOMAIN, COORDINATE>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(dom) = self.iter.next() {
@@ -166,6 +205,36 @@ impl<'ssi, DOMAIN> Iterator for ScaledStepsIter<'ssi, DOMAIN> {
     }
 }
 
+impl<DOMAIN> Scale for ScaledSteps<DOMAIN>
+where
+    DOMAIN: Clone + PartialEq,
+{
+    type Domain = DOMAIN;
+    type Range = f64;
+
+    /// Every step of the domain
+    fn domain_values(&self) -> Vec<DOMAIN> {
+        self.values.clone()
+    }
+
+    fn range_values(&self) -> Vec<f64> {
+        vec![0.0, self.dimension.saturating_sub(1) as f64]
+    }
+
+    /// The exact coordinate of a step
+    fn map_value(&self, value: &DOMAIN) -> Option<f64> {
+        self.float_iter()
+            .find(|step| step.value() == value)
+            .map(|step| step.dimension())
+    }
+
+    fn tick_values(&self) -> Vec<(DOMAIN, f64)> {
+        self.float_iter()
+            .map(|step| (step.value().clone(), step.dimension()))
+            .collect()
+    }
+}
+
 #[test]
 fn empty() {
     let scaled_steps = ScaledSteps::<i8>::new(800);
@@ -187,6 +256,27 @@ where
         })
     );
 }
+#[test]
+fn exact_steps_over_labeled_domain() {
+    let scaled_steps = ScaledSteps::new(50).ordered(vec!["Alpha", "Beta", "Gamma"]);
+    let dimensions: Vec<f64> = scaled_steps
+        .float_iter()
+        .map(|step| step.dimension())
+        .collect();
+
+    assert_eq!(dimensions, vec![12.5, 25.0, 37.5]);
+    assert_eq!(scaled_steps.iter().last().unwrap().dimension(), 36);
+}
+
+#[test]
+fn exact_angles_in_degrees() {
+    let scaled_steps = ScaledSteps::new(800).discrete_range(0..360);
+    let last = scaled_steps.float_iter().last().unwrap();
+
+    assert_eq!(last.value(), &359);
+    assert!((last.dimension() - 359.0 * 800.0 / 360.0).abs() < 1e-9);
+}
+
 #[test]
 fn angles_in_degrees() {
     assert_last(ScaledSteps::new(800).discrete_range(0..360), 360, 718, 359);
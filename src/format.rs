@@ -0,0 +1,517 @@
+//! Number formatting with the specifier mini-language of d3-format
+//!
+//! A specifier has the form `[[fill]align][sign][symbol][0][width][,][.precision][~][type]`,
+//! e.g. `",.2f"` for grouped thousands with two decimals, `".3s"` for three significant
+//! digits with an SI prefix, or `"+.1%"` for a signed percentage.
+
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+    ops::{AddAssign, Sub},
+    str::FromStr,
+};
+
+use crate::{ConvertToFloat, Linear};
+
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// Formatter for numbers, parsed from a d3-format specifier
+///
+/// The types are `e` (exponent notation), `f` (fixed point), `g` (either, depending on the size),
+/// `r` (significant digits), `s` (significant digits with an SI prefix), `%` (fixed point
+/// percentage), `p` (significant digits percentage), `b`, `o`, `d`, `x` and `X` (rounded integers
+/// in base 2, 8, 10 and 16) and `n` (grouped `g`). Without a type, the shortest of up to
+/// 12 significant digits is used.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::NumberFormat;
+///
+/// let money: NumberFormat = "$,.2f".parse().unwrap();
+/// let si: NumberFormat = ".3s".parse().unwrap();
+///
+/// assert_eq!(money.format(-1234.5), "-$1,234.50");
+/// assert_eq!(si.format(42e6), "42.0M");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberFormat {
+    fill: char,
+    align: char,
+    sign: char,
+    symbol: Option<char>,
+    zero: bool,
+    width: usize,
+    comma: bool,
+    precision: usize,
+    trim: bool,
+    kind: char,
+}
+
+#[derive(Debug)]
+pub struct ParseFormatError {
+    explain: String,
+}
+
+impl FromStr for NumberFormat {
+    type Err = ParseFormatError;
+
+    fn from_str(specifier: &str) -> Result<Self, Self::Err> {
+        let error = |reason: &str| ParseFormatError {
+            explain: format!("'{}' {}", specifier, reason),
+        };
+        let chars: Vec<char> = specifier.chars().collect();
+        let mut index = 0;
+        let next_if = |index: &mut usize, accept: &dyn Fn(char) -> bool| match chars.get(*index) {
+            Some(c) if accept(*c) => {
+                *index += 1;
+                Some(*c)
+            }
+            _ => None,
+        };
+        let digits = |index: &mut usize| {
+            let start = *index;
+            while next_if(index, &|c| c.is_ascii_digit()).is_some() {}
+            chars[start..*index]
+                .iter()
+                .collect::<String>()
+                .parse::<usize>()
+                .ok()
+        };
+
+        let (mut fill, mut align) = match chars.as_slice() {
+            [fill, align, ..] if "<>=^".contains(*align) => {
+                index = 2;
+                (*fill, *align)
+            }
+            [align, ..] if "<>=^".contains(*align) => {
+                index = 1;
+                (' ', *align)
+            }
+            _ => (' ', '>'),
+        };
+        let sign = next_if(&mut index, &|c| "+-( ".contains(c)).unwrap_or('-');
+        let symbol = next_if(&mut index, &|c| c == '$' || c == '#');
+        let mut zero = next_if(&mut index, &|c| c == '0').is_some();
+        let width = digits(&mut index).unwrap_or(0);
+        let mut comma = next_if(&mut index, &|c| c == ',').is_some();
+        let precision = match next_if(&mut index, &|c| c == '.') {
+            Some(_) => Some(digits(&mut index).ok_or_else(|| error("lacks a precision"))?),
+            None => None,
+        };
+        let mut trim = next_if(&mut index, &|c| c == '~').is_some();
+        let mut kind = match next_if(&mut index, &|c| "efgrs%pbodxXn".contains(c)) {
+            Some('n') => {
+                comma = true;
+                'g'
+            }
+            Some(kind) => kind,
+            None => '\0',
+        };
+        if index < chars.len() {
+            return Err(error("is not a valid format specifier"));
+        }
+
+        if zero || (fill == '0' && align == '=') {
+            (zero, fill, align) = (true, '0', '=');
+        }
+        let precision = if kind == '\0' {
+            (kind, trim) = ('g', true);
+            precision.unwrap_or(12)
+        } else {
+            precision.unwrap_or(6)
+        };
+        let precision = if "gprs".contains(kind) {
+            precision.clamp(1, 21)
+        } else {
+            precision.min(20)
+        };
+
+        Ok(Self {
+            fill,
+            align,
+            sign,
+            symbol,
+            zero,
+            width,
+            comma,
+            precision,
+            trim,
+            kind,
+        })
+    }
+}
+
+impl NumberFormat {
+    /// The number formatted according to the specifier
+    pub fn format(&self, value: f64) -> String {
+        let mut prefix = String::new();
+        let mut suffix = String::new();
+
+        let negative = value < 0.0 || (value == 0.0 && value.is_sign_negative());
+        let (mut number, si_prefix) = if value.is_nan() {
+            ("NaN".to_string(), "")
+        } else if value.is_infinite() {
+            ("Infinity".to_string(), "")
+        } else {
+            self.format_type(value.abs())
+        };
+        if self.trim {
+            number = trim_insignificant_zeros(&number);
+        }
+        // negative numbers that round to zero lose their sign
+        let negative = negative && (number.parse::<f64>() != Ok(0.0) || self.sign == '+');
+
+        if negative {
+            prefix.push(if self.sign == '(' { '(' } else { '-' });
+        } else if self.sign == '+' || self.sign == ' ' {
+            prefix.push(self.sign);
+        }
+        match (self.symbol, self.kind) {
+            (Some('$'), _) => prefix.push('$'),
+            (Some('#'), 'b' | 'o' | 'x' | 'X') => {
+                prefix.push('0');
+                prefix.push(self.kind.to_ascii_lowercase());
+            }
+            _ => {}
+        }
+
+        // the decimal point, exponent and SI prefix are not part of the grouping and padding
+        if "defgprs%".contains(self.kind) {
+            if let Some(position) = number.find(|c: char| !c.is_ascii_digit()) {
+                suffix.push_str(&number[position..]);
+                number.truncate(position);
+            }
+        }
+        suffix.push_str(si_prefix);
+        if self.symbol != Some('$') && (self.kind == '%' || self.kind == 'p') {
+            suffix.push('%');
+        }
+        if negative && self.sign == '(' {
+            suffix.push(')');
+        }
+
+        if self.comma && !self.zero {
+            number = group_thousands(&number, usize::MAX);
+        }
+        let length = prefix.chars().count() + number.chars().count() + suffix.chars().count();
+        let mut padding = if length < self.width {
+            self.fill.to_string().repeat(self.width - length)
+        } else {
+            String::new()
+        };
+        if self.comma && self.zero {
+            let width = if padding.is_empty() {
+                usize::MAX
+            } else {
+                self.width.saturating_sub(suffix.chars().count())
+            };
+            number = group_thousands(&(padding + &number), width);
+            padding = String::new();
+        }
+
+        match self.align {
+            '<' => prefix + &number + &suffix + &padding,
+            '=' => prefix + &padding + &number + &suffix,
+            '^' => {
+                let half = padding.char_indices().nth(padding.chars().count() / 2);
+                let (before, after) = padding.split_at(half.map_or(padding.len(), |(at, _)| at));
+                before.to_string() + &prefix + &number + &suffix + after
+            }
+            _ => padding + &prefix + &number + &suffix,
+        }
+    }
+
+    /// Closure for the `tick_label` of [horizontal_axis](crate::horizontal_axis) and
+    /// [vertical_axis](crate::vertical_axis), that labels every tick with its formatted domain value
+    ///
+    /// # Example
+    /// ```rust
+    /// # use d3rs::{Document, Group, IterableScale, Linear, NumberFormat};
+    ///
+    /// let y_axis = Linear::try_new(0.0, 0.5, 100).unwrap();
+    /// let percentage: NumberFormat = ".0%".parse().unwrap();
+    ///
+    /// let axis = d3rs::vertical_axis!(
+    ///     (0, 0), (0, 99),
+    ///     y_axis.intervals(0.25),
+    ///     position: 10_usize,
+    ///     tick_label: percentage.tick_label()
+    /// );
+    /// let document = d3rs::svg!(100, 100, [axis]);
+    /// assert!(document.to_string().contains("25%"));
+    /// ```
+    pub fn tick_label<DT, D>(&self) -> impl Fn(DT, D) -> Option<String> + '_
+    where
+        DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+        Linear<DT>: ConvertToFloat<DT>,
+    {
+        move |domain, _| Some(self.format(<Linear<DT> as ConvertToFloat<DT>>::to_float(domain)))
+    }
+
+    /// The digits of a finite, non-negative value, with the SI prefix for type `s`
+    fn format_type(&self, value: f64) -> (String, &'static str) {
+        let precision = self.precision;
+
+        let number = match self.kind {
+            'e' => exponential(value, precision),
+            'f' => fixed(value, precision),
+            'g' => {
+                let (digits, exponent) = significant(value, precision);
+                if exponent < -6 || exponent >= precision as i32 {
+                    exponential(value, precision - 1)
+                } else {
+                    rounded(&digits, exponent)
+                }
+            }
+            'r' => {
+                let (digits, exponent) = significant(value, precision);
+                rounded(&digits, exponent)
+            }
+            's' => return si(value, precision),
+            '%' => fixed(value * 100.0, precision),
+            'p' => {
+                let (digits, exponent) = significant(value * 100.0, precision);
+                rounded(&digits, exponent)
+            }
+            'b' => format!("{:b}", value.round() as u128),
+            'o' => format!("{:o}", value.round() as u128),
+            'x' => format!("{:x}", value.round() as u128),
+            'X' => format!("{:X}", value.round() as u128),
+            _ => fixed(value.round(), 0),
+        };
+
+        (number, "")
+    }
+}
+
+impl Error for ParseFormatError {}
+
+impl Display for ParseFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid format: {}", self.explain)
+    }
+}
+
+/// The exact decimal digits of a finite, non-negative value and the exponent of the first digit
+fn exact_digits(value: f64) -> (Vec<u8>, i32) {
+    // no double has more than 767 significant decimal digits
+    let scientific = format!("{:.767e}", value);
+    let (mantissa, exponent) = scientific
+        .split_once('e')
+        .expect("scientific notation has an exponent");
+
+    (
+        mantissa
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|digit| digit - b'0')
+            .collect(),
+        exponent.parse().expect("the exponent is a number"),
+    )
+}
+
+/// Digits rounded half up to the first `keep` digits, with the possibly increased exponent
+fn round_half_up(mut digits: Vec<u8>, mut exponent: i32, keep: usize) -> (Vec<u8>, i32) {
+    if digits.len() <= keep {
+        digits.resize(keep, 0);
+        return (digits, exponent);
+    }
+
+    let round_up = digits[keep] >= 5;
+    digits.truncate(keep);
+    if round_up {
+        match digits.iter().rposition(|digit| *digit < 9) {
+            Some(position) => {
+                digits[position] += 1;
+                digits[position + 1..].fill(0);
+            }
+            None => {
+                digits.fill(0);
+                digits.insert(0, 1);
+                digits.truncate(keep.max(1));
+                exponent += 1;
+            }
+        }
+    }
+
+    (digits, exponent)
+}
+
+/// The value rounded to a number of significant digits, like `toPrecision` in JavaScript
+fn significant(value: f64, precision: usize) -> (Vec<u8>, i32) {
+    let (digits, exponent) = exact_digits(value);
+
+    round_half_up(digits, exponent, precision)
+}
+
+/// Digits with the decimals needed to show all of them, like d3's rounded notation
+fn rounded(digits: &[u8], exponent: i32) -> String {
+    let decimals = (digits.len() as i32 - 1 - exponent).max(0) as usize;
+
+    render_fixed(digits, exponent, decimals)
+}
+
+fn render_fixed(digits: &[u8], exponent: i32, decimals: usize) -> String {
+    let digit_at = |position: i32| {
+        usize::try_from(exponent - position)
+            .ok()
+            .and_then(|index| digits.get(index))
+            .map_or('0', |digit| char::from(b'0' + digit))
+    };
+
+    let mut number: String = (0..=exponent.max(0)).rev().map(digit_at).collect();
+    if decimals > 0 {
+        number.push('.');
+        number.extend((1..=decimals as i32).map(|position| digit_at(-position)));
+    }
+
+    number
+}
+
+/// Fixed point notation, like `toFixed` in JavaScript
+fn fixed(value: f64, decimals: usize) -> String {
+    let (digits, exponent) = exact_digits(value);
+    let keep = exponent + 1 + decimals as i32;
+
+    if keep < 0 {
+        render_fixed(&[], 0, decimals)
+    } else {
+        let (digits, exponent) = round_half_up(digits, exponent, keep as usize);
+        render_fixed(&digits, exponent, decimals)
+    }
+}
+
+/// Exponent notation with a number of decimals, like `toExponential` in JavaScript
+fn exponential(value: f64, decimals: usize) -> String {
+    let (digits, exponent) = significant(value, decimals + 1);
+
+    let mantissa: String = digits
+        .iter()
+        .map(|digit| char::from(b'0' + digit))
+        .collect();
+    let (first, rest) = mantissa.split_at(1);
+
+    format!(
+        "{}{}{}e{}{}",
+        first,
+        if rest.is_empty() { "" } else { "." },
+        rest,
+        if exponent < 0 { '-' } else { '+' },
+        exponent.abs()
+    )
+}
+
+/// Significant digits scaled to the nearest SI prefix
+fn si(value: f64, precision: usize) -> (String, &'static str) {
+    let (digits, exponent) = significant(value, precision);
+    let prefix_exponent = exponent.div_euclid(3).clamp(-8, 8);
+    let integer_digits = exponent - prefix_exponent * 3 + 1;
+
+    let number = if integer_digits > 0 {
+        render_fixed(
+            &digits,
+            integer_digits - 1,
+            (digits.len() as i32 - integer_digits).max(0) as usize,
+        )
+    } else {
+        // below the smallest prefix, with fewer significant digits
+        let (digits, exponent) = significant(
+            value,
+            (precision as i32 + integer_digits - 1).max(1) as usize,
+        );
+        render_fixed(&digits, exponent - prefix_exponent * 3, precision - 1)
+    };
+
+    (number, SI_PREFIXES[(prefix_exponent + 8) as usize])
+}
+
+/// Removes the zeros after the decimal point that do not add precision
+fn trim_insignificant_zeros(number: &str) -> String {
+    let fraction_end = number
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(number.len());
+    let (mantissa, rest) = number.split_at(fraction_end);
+
+    if mantissa.contains('.') {
+        mantissa
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+            + rest
+    } else {
+        number.to_string()
+    }
+}
+
+/// Groups the digits by thousands, limiting the result to a width by dropping leading digits
+fn group_thousands(digits: &str, width: usize) -> String {
+    let chars: Vec<char> = digits.chars().collect();
+    let mut groups = Vec::new();
+    let mut end = chars.len();
+    let mut length = 0;
+    let mut group = 3;
+
+    while end > 0 && group > 0 {
+        if length + group + 1 > width {
+            group = usize::max(1, width.saturating_sub(length));
+        }
+        let start = end.saturating_sub(group);
+        groups.push(chars[start..end].iter().collect::<String>());
+        end = start;
+        length += group + 1;
+        if length > width {
+            break;
+        }
+        group = 3;
+    }
+    groups.reverse();
+
+    groups.join(",")
+}
+
+#[test]
+fn format_specifiers() {
+    let format =
+        |specifier: &str, value: f64| specifier.parse::<NumberFormat>().unwrap().format(value);
+
+    assert_eq!(format(",.2f", 1234.567), "1,234.57");
+    assert_eq!(format(".3s", 42e6), "42.0M");
+    assert_eq!(format(".3s", 0.00123), "1.23m");
+    assert_eq!(format("~s", 1500.0), "1.5k");
+    assert_eq!(format("$,.0f", 1234.5), "$1,235");
+    assert_eq!(format("+.1%", 0.123), "+12.3%");
+    assert_eq!(format("+.1%", -0.00001), "-0.0%");
+    assert_eq!(format(".1f", -0.01), "0.0");
+    assert_eq!(format("#x", 255.0), "0xff");
+    assert_eq!(format("#b", 5.0), "0b101");
+    assert_eq!(format("d", 2.5), "3");
+    assert_eq!(format(".2e", 12345.0), "1.23e+4");
+    assert_eq!(format(".3g", 0.0001234), "0.000123");
+    assert_eq!(format(".3g", 1234.0), "1.23e+3");
+    assert_eq!(format(".2r", 0.0999), "0.10");
+    assert_eq!(format(".2p", 0.0123), "1.2%");
+    assert_eq!(format("", 0.1 + 0.2), "0.3");
+    assert_eq!(format("n", 123456.7), "123,457");
+    assert_eq!(format("n", 1234567.0), "1.23457e+6");
+    assert_eq!(format("(.1f", -3.0), "(3.0)");
+}
+
+#[test]
+fn format_padding() {
+    let format =
+        |specifier: &str, value: f64| specifier.parse::<NumberFormat>().unwrap().format(value);
+
+    assert_eq!(format(">4.1f", -0.5), "-0.5");
+    assert_eq!(format("8d", 42.0), "      42");
+    assert_eq!(format("<8d", 42.0), "42      ");
+    assert_eq!(format("*^7d", 42.0), "**42***");
+    assert_eq!(format("+08.1f", 3.0), "+00003.0");
+    assert_eq!(format("012,d", 1234.0), "0,000,001,234");
+    assert_eq!(format("=+8d", -42.0), "-     42");
+
+    assert!("..2f".parse::<NumberFormat>().is_err());
+    assert!(".f".parse::<NumberFormat>().is_err());
+    assert!(",.2q".parse::<NumberFormat>().is_err());
+}
@@ -0,0 +1,555 @@
+//! Interpolators, that blend between two or more values for `t` from 0 to 1
+//!
+//! Each function returns a closure that can be called repeatedly, e.g. once per frame
+//! of an animation or once per stop of a gradient.
+
+use crate::{Angle, Color, Hsl, Lab, LengthOrPercentage, Srgb, Transform, TransformFunction};
+
+/// Linear interpolation between two numbers
+pub fn interpolate_number(a: f64, b: f64) -> impl Fn(f64) -> f64 {
+    move |t| a + (b - a) * t
+}
+
+/// Interpolation of each of the red, green and blue channels, and the opacity
+pub fn interpolate_rgb(a: &Color, b: &Color) -> impl Fn(f64) -> Color {
+    let (a, b) = (Srgb::from(a), Srgb::from(b));
+
+    move |t| {
+        Srgb {
+            red: channel(a.red, b.red, t),
+            green: channel(a.green, b.green, t),
+            blue: channel(a.blue, b.blue, t),
+            opacity: channel(a.opacity, b.opacity, t),
+        }
+        .into()
+    }
+}
+
+/// Interpolation of the hue along the shortest path around the colour wheel,
+/// and of the saturation, the lightness and the opacity
+pub fn interpolate_hsl(a: &Color, b: &Color) -> impl Fn(f64) -> Color {
+    let (a, b) = (Hsl::from(a), Hsl::from(b));
+
+    move |t| {
+        Hsl {
+            hue: hue(a.hue, b.hue, t),
+            saturation: channel(a.saturation, b.saturation, t),
+            lightness: channel(a.lightness, b.lightness, t),
+            opacity: channel(a.opacity, b.opacity, t),
+        }
+        .into()
+    }
+}
+
+/// Interpolation in the perceptually uniform CIELAB space
+pub fn interpolate_lab(a: &Color, b: &Color) -> impl Fn(f64) -> Color {
+    let (a, b) = (Lab::from(a), Lab::from(b));
+
+    move |t| {
+        Lab {
+            lightness: channel(a.lightness, b.lightness, t),
+            a: channel(a.a, b.a, t),
+            b: channel(a.b, b.b, t),
+            opacity: channel(a.opacity, b.opacity, t),
+        }
+        .into()
+    }
+}
+
+/// Interpolation of the numbers embedded in the second string, starting from
+/// the numbers at the same position in the first string
+///
+/// The text around the numbers is taken from the second string; numbers without
+/// a counterpart in the first string stay as they are in the second.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::interpolate_string;
+///
+/// let size = interpolate_string("300px 12pt", "500px 16pt bold");
+///
+/// assert_eq!(size(0.25), "350px 13pt bold");
+/// ```
+pub fn interpolate_string(a: &str, b: &str) -> impl Fn(f64) -> String {
+    enum Piece {
+        Text(String),
+        Number(f64, f64),
+    }
+
+    let from: Vec<f64> = numbers(a)
+        .into_iter()
+        .filter_map(|(start, end)| a[start..end].parse().ok())
+        .collect();
+
+    let mut pieces = Vec::new();
+    let mut text_start = 0;
+    for (index, (start, end)) in numbers(b).into_iter().enumerate() {
+        pieces.push(Piece::Text(b[text_start..start].to_string()));
+        match b[start..end].parse::<f64>() {
+            Ok(to) => pieces.push(Piece::Number(from.get(index).copied().unwrap_or(to), to)),
+            Err(_) => pieces.push(Piece::Text(b[start..end].to_string())),
+        }
+        text_start = end;
+    }
+    pieces.push(Piece::Text(b[text_start..].to_string()));
+
+    move |t| {
+        pieces
+            .iter()
+            .map(|piece| match piece {
+                Piece::Text(text) => text.clone(),
+                Piece::Number(from, to) => (from + (to - from) * t).to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Interpolation between two transforms
+///
+/// When both transforms consist of the same kinds of functions, possibly after padding the
+/// shorter one with identity functions, each function is interpolated on its own.
+/// Otherwise, translations in user units and rotations are combined into a single
+/// translation and rotation, with the rotation taking the shortest path.
+/// Transforms that cannot be combined switch halfway.
+pub fn interpolate_transform(a: &Transform, b: &Transform) -> impl Fn(f64) -> Transform {
+    enum Plan {
+        Pairwise(Vec<(TransformFunction, TransformFunction)>),
+        Combined((f64, f64, f64), (f64, f64, f64)),
+        Switch(Transform, Transform),
+    }
+
+    let pairs: Vec<(TransformFunction, TransformFunction)> =
+        (0..usize::max(a.functions().len(), b.functions().len()))
+            .map(
+                |index| match (a.functions().get(index), b.functions().get(index)) {
+                    (Some(from), Some(to)) => (from.clone(), to.clone()),
+                    (Some(from), None) => (from.clone(), identity(from)),
+                    (None, Some(to)) => (identity(to), to.clone()),
+                    (None, None) => {
+                        unreachable!("the index is below the length of either transform")
+                    }
+                },
+            )
+            .collect();
+
+    let plan = if pairs
+        .iter()
+        .all(|(from, to)| interpolate_function(from, to, 0.0).is_some())
+    {
+        Plan::Pairwise(pairs)
+    } else {
+        match (combine(a), combine(b)) {
+            (Some(mut from), Some(mut to)) => {
+                // rotate along the shortest path
+                if from.2 - to.2 > 180.0 {
+                    to.2 += 360.0;
+                } else if to.2 - from.2 > 180.0 {
+                    from.2 += 360.0;
+                }
+                Plan::Combined(from, to)
+            }
+            _ => Plan::Switch(a.clone(), b.clone()),
+        }
+    };
+
+    move |t| match &plan {
+        Plan::Pairwise(pairs) => {
+            let mut functions = pairs
+                .iter()
+                .filter_map(|(from, to)| interpolate_function(from, to, t));
+            let first = functions
+                .next()
+                .expect("transforms have at least one function");
+            functions.fold(Transform::new(first), Transform::and_then)
+        }
+        Plan::Combined(from, to) => Transform::new(TransformFunction::Translate {
+            x: LengthOrPercentage::Decimal(channel(from.0, to.0, t)),
+            y: LengthOrPercentage::Decimal(channel(from.1, to.1, t)),
+        })
+        .and_then(TransformFunction::Rotate(Angle::Degrees(
+            channel(from.2, to.2, t) as f32,
+        ))),
+        Plan::Switch(from, to) => {
+            if t < 0.5 {
+                from.clone()
+            } else {
+                to.clone()
+            }
+        }
+    }
+}
+
+/// Piecewise interpolation between consecutive values, each piece taking an equal
+/// part of the unit interval, or [None] without values
+///
+/// # Example
+/// ```rust
+/// # use d3rs::{interpolate_number, interpolate_piecewise};
+///
+/// let piecewise =
+///     interpolate_piecewise(|a, b| interpolate_number(*a, *b), &[0.0, 10.0, 30.0]).unwrap();
+///
+/// assert_eq!(piecewise(0.25), 5.0);
+/// assert_eq!(piecewise(0.75), 20.0);
+/// ```
+pub fn interpolate_piecewise<T, R, F, I>(interpolate: F, values: &[T]) -> Option<impl Fn(f64) -> R>
+where
+    F: Fn(&T, &T) -> I,
+    I: Fn(f64) -> R,
+{
+    let interpolators: Vec<I> = match values {
+        [] => return None,
+        [only] => vec![interpolate(only, only)],
+        _ => values
+            .windows(2)
+            .map(|pair| interpolate(&pair[0], &pair[1]))
+            .collect(),
+    };
+    let n = interpolators.len();
+
+    Some(move |t: f64| {
+        let scaled = t * n as f64;
+        let index = usize::min(f64::floor(scaled).max(0.0) as usize, n - 1);
+
+        interpolators[index](scaled - index as f64)
+    })
+}
+
+/// Uniform B-spline through the values, which passes through the first and the last value
+pub fn interpolate_basis(values: Vec<f64>) -> impl Fn(f64) -> f64 {
+    move |t| basis(&values, t)
+}
+
+/// Closed uniform B-spline through the values, which wraps around at 0 and 1
+pub fn interpolate_basis_closed(values: Vec<f64>) -> impl Fn(f64) -> f64 {
+    move |t| {
+        let n = values.len();
+        if n == 0 {
+            return f64::NAN;
+        }
+
+        let t = t.rem_euclid(1.0);
+        let i = f64::floor(t * n as f64) as usize;
+
+        basis_segment(
+            (t - i as f64 / n as f64) * n as f64,
+            [
+                values[(i + n - 1) % n],
+                values[i % n],
+                values[(i + 1) % n],
+                values[(i + 2) % n],
+            ],
+        )
+    }
+}
+
+/// Uniform B-spline through the values, evaluated at `t` in `0.0..=1.0`
+pub(crate) fn basis(values: &[f64], t: f64) -> f64 {
+    match values {
+        [] => f64::NAN,
+        [only] => *only,
+        _ => {
+            let n = values.len() - 1;
+            let t = t.clamp(0.0, 1.0);
+            let i = usize::min(f64::floor(t * n as f64) as usize, n - 1);
+
+            let (v1, v2) = (values[i], values[i + 1]);
+            let v0 = if i > 0 { values[i - 1] } else { 2.0 * v1 - v2 };
+            let v3 = if i < n - 1 {
+                values[i + 2]
+            } else {
+                2.0 * v2 - v1
+            };
+
+            basis_segment((t - i as f64 / n as f64) * n as f64, [v0, v1, v2, v3])
+        }
+    }
+}
+
+fn basis_segment(t1: f64, [v0, v1, v2, v3]: [f64; 4]) -> f64 {
+    let (t2, t3) = (t1 * t1, t1 * t1 * t1);
+
+    ((1.0 - 3.0 * t1 + 3.0 * t2 - t3) * v0
+        + (4.0 - 6.0 * t2 + 3.0 * t3) * v1
+        + (1.0 + 3.0 * t1 + 3.0 * t2 - 3.0 * t3) * v2
+        + t3 * v3)
+        / 6.0
+}
+
+/// Linear interpolation, where a missing value (NaN) takes the other value
+fn channel(a: f64, b: f64, t: f64) -> f64 {
+    if a.is_nan() {
+        b
+    } else if b.is_nan() {
+        a
+    } else {
+        a + (b - a) * t
+    }
+}
+
+/// Interpolation of a hue in degrees along the shortest path
+fn hue(a: f64, b: f64, t: f64) -> f64 {
+    let difference = b - a;
+
+    if difference.abs() > 180.0 {
+        channel(
+            a,
+            a + difference - 360.0 * f64::round(difference / 360.0),
+            t,
+        )
+    } else {
+        channel(a, b, t)
+    }
+}
+
+/// Byte ranges of the numbers in a string
+fn numbers(text: &str) -> Vec<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let digits_from = |mut index: usize| {
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        index
+    };
+
+    let mut ranges = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        let start = index;
+        let mut position = index;
+        if matches!(bytes[position], b'+' | b'-') {
+            position += 1;
+        }
+        let integer_end = digits_from(position);
+        let mut end = integer_end;
+        if end < bytes.len() && bytes[end] == b'.' {
+            end = digits_from(end + 1);
+        }
+
+        if end - position > 1 || (end > position && bytes[position] != b'.') {
+            // an optional exponent
+            if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+                let mut exponent = end + 1;
+                if exponent < bytes.len() && matches!(bytes[exponent], b'+' | b'-') {
+                    exponent += 1;
+                }
+                let exponent_end = digits_from(exponent);
+                if exponent_end > exponent {
+                    end = exponent_end;
+                }
+            }
+            ranges.push((start, end));
+            index = end;
+        } else {
+            index += 1;
+        }
+    }
+
+    ranges
+}
+
+/// The function that does not transform, of the same kind as the given one
+fn identity(function: &TransformFunction) -> TransformFunction {
+    match function {
+        TransformFunction::Translate { .. } => TransformFunction::Translate {
+            x: LengthOrPercentage::ZERO,
+            y: LengthOrPercentage::ZERO,
+        },
+        TransformFunction::Rotate(_) => TransformFunction::Rotate(Angle::Degrees(0.0)),
+    }
+}
+
+fn interpolate_function(
+    from: &TransformFunction,
+    to: &TransformFunction,
+    t: f64,
+) -> Option<TransformFunction> {
+    match (from, to) {
+        (
+            TransformFunction::Translate {
+                x: from_x,
+                y: from_y,
+            },
+            TransformFunction::Translate { x: to_x, y: to_y },
+        ) => Some(TransformFunction::Translate {
+            x: interpolate_length(from_x, to_x, t)?,
+            y: interpolate_length(from_y, to_y, t)?,
+        }),
+        (TransformFunction::Rotate(from), TransformFunction::Rotate(to)) => {
+            let angle = |from: f32, to: f32| from + (to - from) * t as f32;
+
+            Some(TransformFunction::Rotate(match (from, to) {
+                (Angle::Radians(from), Angle::Radians(to)) => Angle::Radians(angle(*from, *to)),
+                (Angle::Turns(from), Angle::Turns(to)) => Angle::Turns(angle(*from, *to)),
+                _ => Angle::Degrees(angle(from.to_degrees(), to.to_degrees())),
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// Interpolation between two lengths in the same unit; zero fits any unit
+fn interpolate_length(
+    from: &LengthOrPercentage,
+    to: &LengthOrPercentage,
+    t: f64,
+) -> Option<LengthOrPercentage> {
+    let (from_value, from_unit) = length_with_unit(from)?;
+    let (to_value, to_unit) = length_with_unit(to)?;
+    let unit = if from_unit == to_unit || (from_value == 0.0 && from_unit.is_empty()) {
+        to_unit
+    } else if to_value == 0.0 && to_unit.is_empty() {
+        from_unit
+    } else {
+        return None;
+    };
+    let value = from_value + (to_value - from_value) * t;
+
+    Some(match unit {
+        "" => LengthOrPercentage::Decimal(value),
+        "in" => LengthOrPercentage::Inch(value as f32),
+        "cm" => LengthOrPercentage::Cm(value as f32),
+        "mm" => LengthOrPercentage::Mm(value as f32),
+        "%" => LengthOrPercentage::Percentage(value as f32),
+        _ => LengthOrPercentage::Raw(format!("{}{}", LengthOrPercentage::Decimal(value), unit)),
+    })
+}
+
+fn length_with_unit(length: &LengthOrPercentage) -> Option<(f64, &'static str)> {
+    match length {
+        LengthOrPercentage::Number(number) => Some((*number as f64, "")),
+        LengthOrPercentage::Decimal(decimal) => Some((*decimal, "")),
+        LengthOrPercentage::Em(em) => Some((*em as f64, "em")),
+        LengthOrPercentage::Ex(ex) => Some((*ex as f64, "ex")),
+        LengthOrPercentage::Pixels(px) => Some((*px as f64, "px")),
+        LengthOrPercentage::Inch(inch) => Some((*inch as f64, "in")),
+        LengthOrPercentage::Cm(cm) => Some((*cm as f64, "cm")),
+        LengthOrPercentage::Mm(mm) => Some((*mm as f64, "mm")),
+        LengthOrPercentage::Point(pt) => Some((*pt as f64, "pt")),
+        LengthOrPercentage::Pica(pc) => Some((*pc as f64, "pc")),
+        LengthOrPercentage::Percentage(percentage) => Some((*percentage as f64, "%")),
+        LengthOrPercentage::Raw(_) => None,
+    }
+}
+
+/// The translation in user units and the rotation in degrees of a transform,
+/// or [None] when it has lengths in other units
+fn combine(transform: &Transform) -> Option<(f64, f64, f64)> {
+    // the affine matrix [a c e; b d f]
+    let (mut a, mut b, mut c, mut d, mut e, mut f) = (1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    for function in transform.functions() {
+        match function {
+            TransformFunction::Translate { x, y } => {
+                let (x, y) = match (length_with_unit(x)?, length_with_unit(y)?) {
+                    ((x, "" | "px"), (y, "" | "px")) => (x, y),
+                    _ => return None,
+                };
+                e += a * x + c * y;
+                f += b * x + d * y;
+            }
+            TransformFunction::Rotate(angle) => {
+                let (sin, cos) = (angle.to_degrees() as f64).to_radians().sin_cos();
+                (a, b, c, d) = (
+                    a * cos + c * sin,
+                    b * cos + d * sin,
+                    c * cos - a * sin,
+                    d * cos - b * sin,
+                );
+            }
+        }
+    }
+
+    Some((e, f, b.atan2(a).to_degrees()))
+}
+
+#[test]
+fn interpolate_numbers_and_colors() {
+    let number = interpolate_number(10.0, -10.0);
+    assert_eq!(number(0.25), 5.0);
+
+    let red = Color::Hex(0xff0000);
+    let blue = Color::Hex(0x0000ff);
+    assert_eq!(interpolate_rgb(&red, &blue)(0.5), Color::Hex(0x800080));
+    // the hue turns from 0 through 300 to 240 degrees
+    assert_eq!(interpolate_hsl(&red, &blue)(0.5), Color::Hex(0xff00ff));
+    assert_eq!(interpolate_lab(&red, &blue)(0.0), red);
+    assert_eq!(interpolate_lab(&red, &blue)(1.0), blue);
+
+    // the missing hue of grey takes the hue of the other colour
+    let grey = Color::Hex(0x808080);
+    assert_eq!(
+        Hsl::from(interpolate_hsl(&grey, &red)(0.5)).hue,
+        Hsl::from(&red).hue
+    );
+}
+
+#[test]
+fn interpolate_strings() {
+    let string = interpolate_string("M0,0 L10,-5e1", "M100,50 L20,50 Z 7");
+
+    assert_eq!(string(0.0), "M0,0 L10,-50 Z 7");
+    assert_eq!(string(0.5), "M50,25 L15,0 Z 7");
+    assert_eq!(string(1.0), "M100,50 L20,50 Z 7");
+
+    assert_eq!(interpolate_string("a.5b", "a1.5b.")(0.5), "a1b.");
+}
+
+#[test]
+fn interpolate_transforms() {
+    let from = Transform::new(TransformFunction::Translate {
+        x: LengthOrPercentage::Number(0),
+        y: LengthOrPercentage::Cm(1.0),
+    });
+    let to = Transform::new(TransformFunction::Translate {
+        x: LengthOrPercentage::Decimal(10.0),
+        y: LengthOrPercentage::Cm(3.0),
+    })
+    .and_then(TransformFunction::Rotate(Angle::Degrees(90.0)));
+
+    let pairwise = interpolate_transform(&from, &to);
+    assert_eq!(
+        pairwise(0.5).to_string(),
+        "transform: translate( 5, 2cm ) rotate( 45deg )"
+    );
+
+    let rotated = Transform::new(TransformFunction::Rotate(Angle::Degrees(350.0)));
+    let translated = Transform::new(TransformFunction::Translate {
+        x: LengthOrPercentage::Number(20),
+        y: LengthOrPercentage::Number(0),
+    })
+    .and_then(TransformFunction::Rotate(Angle::Degrees(10.0)));
+    let combined = interpolate_transform(&rotated, &translated)(0.5);
+    assert_eq!(
+        combined.functions()[0],
+        TransformFunction::Translate {
+            x: LengthOrPercentage::Decimal(10.0),
+            y: LengthOrPercentage::Decimal(0.0),
+        }
+    );
+    // through 0 instead of 180 degrees
+    match combined.functions()[1] {
+        TransformFunction::Rotate(Angle::Degrees(degrees)) => assert!(degrees.abs() < 1e-4),
+        _ => panic!("expected a rotation in degrees"),
+    }
+
+    let percentage = Transform::new(TransformFunction::Rotate(Angle::Turns(1.0)));
+    let switched = interpolate_transform(&percentage, &from);
+    assert_eq!(switched(0.4), percentage);
+    assert_eq!(switched(0.6), from);
+}
+
+#[test]
+fn interpolate_splines() {
+    let piecewise = interpolate_piecewise(interpolate_rgb, &[Color::Hex(0x000000)]).unwrap();
+    assert_eq!(piecewise(0.7), Color::Hex(0x000000));
+    assert!(interpolate_piecewise(|a: &f64, b: &f64| interpolate_number(*a, *b), &[]).is_none());
+
+    let basis = interpolate_basis(vec![0.0, 10.0, 0.0]);
+    assert_eq!(basis(0.0), 0.0);
+    assert!((basis(0.5) - 10.0 * 4.0 / 6.0).abs() < 1e-9);
+    assert_eq!(basis(1.0), 0.0);
+
+    let closed = interpolate_basis_closed(vec![0.0, 6.0, 0.0]);
+    assert!((closed(0.0) - closed(1.0)).abs() < 1e-9);
+}
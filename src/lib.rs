@@ -4,6 +4,12 @@ pub use chart::Chart;
 mod view;
 pub use view::View;
 
+mod timestamp;
+pub use timestamp::*;
+
+pub mod time_interval;
+pub use time_interval::*;
+
 mod continuous_mapper;
 pub use continuous_mapper::*;
 
@@ -12,5 +18,14 @@ pub mod data_collections;
 pub mod scales;
 pub use scales::*;
 
+pub mod interpolate;
+pub use interpolate::*;
+
+pub mod format;
+pub use format::*;
+
+pub mod time_format;
+pub use time_format::*;
+
 mod svg;
 pub use svg::*;
@@ -1,16 +1,179 @@
 use std::{
     fmt::{Debug, Display},
-    ops::{AddAssign, Sub},
+    ops::Sub,
 };
 
 pub type Result<T> = std::result::Result<T, ScaleError>;
 
 pub trait DomainScale<DT>
 where
-    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT>,
 {
-    fn domain_to_coordinate(&self, codomain: DT) -> Option<usize>;
+    /// The exact, sub-pixel, coordinate of a domain value
+    fn domain_to_float_coordinate(&self, codomain: DT) -> Option<f64>;
     fn coordinate_to_domain(&self, coordinate: usize) -> Option<DT>;
+
+    /// The coordinate of a domain value, rounded to the nearest pixel,
+    /// or [None] when that coordinate would be negative
+    fn domain_to_coordinate(&self, codomain: DT) -> Option<usize> {
+        self.domain_to_float_coordinate(codomain)
+            .map(f64::round)
+            .filter(|coordinate| *coordinate >= 0.0)
+            .map(|coordinate| coordinate as usize)
+    }
+}
+
+pub trait IterableScale<DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT>,
+{
+    type Iter: Iterator<Item = (DT, usize)>;
+
+    fn iter(&self) -> Self::Iter;
+
+    /// Domain values at intervals of `step`, the difference between two domain values
+    fn intervals(&self, step: <DT as Sub>::Output) -> Self::Iter;
+}
+
+/// Common interface of all scales, mapping values of a domain onto values of a range
+///
+/// Positional scales map onto exact coordinates, the other scales onto e.g. colours or
+/// categories. The trait can be used as a trait object, so charts and axes can take any
+/// scale with the right types, like a `&dyn Scale<Domain = f64, Range = f64>`.
+/// The [Ordinal](super::Ordinal) scale implements it read-only, never growing its domain.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::{Linear, Log, Scale};
+///
+/// fn tick_positions(scale: &dyn Scale<Domain = f64, Range = f64>) -> Vec<f64> {
+///     scale.tick_values().into_iter().map(|(_, coordinate)| coordinate).collect()
+/// }
+///
+/// let linear = Linear::try_new(0.0, 1.0, 101).unwrap();
+/// let log = Log::try_new(1.0, 100.0, 101).unwrap();
+///
+/// assert_eq!(tick_positions(&linear).len(), 11);
+/// assert_eq!(tick_positions(&log).first(), Some(&0.0));
+/// assert_eq!(tick_positions(&log).last(), Some(&100.0));
+/// ```
+pub trait Scale {
+    type Domain;
+    type Range;
+
+    /// The start and the end of a continuous domain, or every value of a discrete one
+    fn domain_values(&self) -> Vec<Self::Domain>;
+
+    /// The start and the end of a continuous range, or every value of a discrete one
+    fn range_values(&self) -> Vec<Self::Range>;
+
+    /// The range value of a domain value, or [None] when the value does not map
+    fn map_value(&self, value: &Self::Domain) -> Option<Self::Range>;
+
+    /// The domain value of a range value, for the scales that can be inverted
+    fn invert_value(&self, _value: &Self::Range) -> Option<Self::Domain> {
+        None
+    }
+
+    /// Representative domain values together with their range values, e.g. for an axis or a legend
+    fn tick_values(&self) -> Vec<(Self::Domain, Self::Range)>;
+}
+
+/// How a scale maps values that lie outside of its domain, or coordinates outside of its range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutOfDomain {
+    /// There is no coordinate for the value, nor a value for the coordinate
+    #[default]
+    Discard,
+    /// Values are pinned to the nearest edge of the domain, coordinates to the nearest edge of the range
+    Clamp,
+    /// Values map to coordinates outside of the range, which may be negative,
+    /// leaving it to the renderer to clip them
+    Extrapolate,
+}
+
+/// The coordinates onto which a scale maps its domain
+///
+/// The range may be reversed, e.g. to let a vertical axis grow upwards
+/// from `height - margin` to `margin`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordinateRange {
+    start: usize,
+    end: usize,
+}
+
+impl CoordinateRange {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Number of coordinates in the range, including both the start and the end
+    pub fn dimension(&self) -> usize {
+        self.start.abs_diff(self.end) + 1
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.end < self.start
+    }
+
+    /// The coordinate at an exact offset from the start of the range
+    pub fn coordinate(&self, offset: f64) -> f64 {
+        if self.is_reversed() {
+            self.start as f64 - offset
+        } else {
+            self.start as f64 + offset
+        }
+    }
+
+    /// The offset from the start of the range of an exact coordinate,
+    /// which is negative for coordinates that lie before the start
+    pub fn float_offset(&self, coordinate: f64) -> f64 {
+        if self.is_reversed() {
+            self.start as f64 - coordinate
+        } else {
+            coordinate - self.start as f64
+        }
+    }
+
+    /// The coordinate at a whole offset from the start of the range
+    pub fn whole_coordinate(&self, offset: usize) -> usize {
+        if self.is_reversed() {
+            self.start.saturating_sub(offset)
+        } else {
+            self.start + offset
+        }
+    }
+
+    /// The offset of a coordinate from the start of the range,
+    /// or [None] when the coordinate lies before the start
+    pub fn offset(&self, coordinate: usize) -> Option<usize> {
+        if self.is_reversed() {
+            self.start.checked_sub(coordinate)
+        } else {
+            coordinate.checked_sub(self.start)
+        }
+    }
+}
+
+/// The error for a range with fewer coordinates than the scale needs
+pub(crate) fn too_small_range(range: CoordinateRange, minimum: usize) -> ScaleError {
+    ScaleError::InvalidRange {
+        explain: format!(
+            "the range from {} to {} has {} coordinates; at least {} are needed",
+            range.start(),
+            range.end(),
+            range.dimension(),
+            minimum
+        ),
+    }
 }
 
 #[derive(Debug)]
@@ -18,6 +181,9 @@ pub enum ScaleError {
     DimensionTooSmall,
     OutOfRange { explain: String },
     RangeExceedsMaximum { explain: String },
+    InvalidDomain { explain: String },
+    InvalidRange { explain: String },
+    InvalidParameter { explain: String },
 }
 
 impl std::error::Error for ScaleError {}
@@ -31,7 +197,120 @@ impl Display for ScaleError {
             ScaleError::RangeExceedsMaximum { explain } => {
                 f.write_fmt(format_args!("Range too large {}", explain))
             }
+            ScaleError::InvalidDomain { explain } => {
+                f.write_fmt(format_args!("Invalid domain: {}", explain))
+            }
+            ScaleError::InvalidRange { explain } => {
+                f.write_fmt(format_args!("Invalid range: {}", explain))
+            }
+            ScaleError::InvalidParameter { explain } => {
+                f.write_fmt(format_args!("Invalid parameter: {}", explain))
+            }
             ScaleError::DimensionTooSmall => f.write_str("Dimension is too small."),
         }
     }
 }
+
+#[test]
+fn coordinate_ranges() {
+    let upwards = CoordinateRange::new(620, 20);
+    assert!(upwards.is_reversed());
+    assert_eq!(upwards.dimension(), 601);
+    assert_eq!(upwards.coordinate(0.5), 619.5);
+    assert_eq!(upwards.whole_coordinate(600), 20);
+    assert_eq!(upwards.offset(20), Some(600));
+    assert_eq!(upwards.offset(621), None);
+
+    let rightwards = CoordinateRange::new(30, 1469);
+    assert!(!rightwards.is_reversed());
+    assert_eq!(rightwards.dimension(), 1440);
+    assert_eq!(rightwards.whole_coordinate(10), 40);
+    assert_eq!(rightwards.offset(29), None);
+}
+
+#[test]
+fn scales_behind_one_trait() {
+    use super::{
+        interpolate_greys, Band, Linear, Log, Ordinal, Point, Pow, Quantize, Sequential, SymLog,
+        Threshold,
+    };
+    use crate::{Color, ScaledSteps};
+
+    let positional: Vec<Box<dyn Scale<Domain = f64, Range = f64>>> = vec![
+        Box::new(Linear::try_new(0.0, 100.0, 101).unwrap()),
+        Box::new(Log::try_new(1.0, 100.0, 101).unwrap()),
+        Box::new(Pow::try_sqrt(0.0, 100.0, 101).unwrap()),
+        Box::new(SymLog::try_new(0.0, 100.0, 101).unwrap()),
+    ];
+    for scale in &positional {
+        let domain = scale.domain_values();
+        assert_eq!(scale.range_values(), vec![0.0, 100.0]);
+        assert_eq!(scale.map_value(&domain[0]), Some(0.0));
+        assert_eq!(scale.map_value(&domain[1]), Some(100.0));
+        assert_eq!(scale.map_value(&1_000.0), None);
+
+        let inverted = scale
+            .invert_value(&scale.map_value(&10.0).unwrap())
+            .unwrap();
+        assert!((inverted - 10.0).abs() < 1e-9);
+        assert_eq!(scale.invert_value(&101.0), None);
+
+        for (value, coordinate) in scale.tick_values() {
+            assert_eq!(scale.map_value(&value), Some(coordinate));
+        }
+    }
+
+    let upwards = Linear::try_new(0_u32, 10, 11)
+        .unwrap()
+        .range(120, 20)
+        .unwrap();
+    assert_eq!(upwards.domain_values(), vec![0, 10]);
+    assert_eq!(upwards.range_values(), vec![120.0, 20.0]);
+    assert_eq!(upwards.invert_value(&70.0), Some(5));
+    assert_eq!(upwards.tick_values().last(), Some(&(10, 20.0)));
+
+    let fruits = vec!["Apples", "Pears", "Bananas"];
+    let categorical: Vec<Box<dyn Scale<Domain = &str, Range = f64>>> = vec![
+        Box::new(Band::try_new(fruits.clone(), 300).unwrap()),
+        Box::new(Point::try_new(fruits.clone(), 301).unwrap()),
+        Box::new(ScaledSteps::new(300).ordered(fruits.clone())),
+    ];
+    for scale in &categorical {
+        assert_eq!(scale.domain_values(), fruits);
+        assert_eq!(scale.map_value(&"Cherries"), None);
+
+        let ticks = scale.tick_values();
+        assert_eq!(ticks.len(), 3);
+        for (value, coordinate) in ticks {
+            assert_eq!(scale.map_value(&value), Some(coordinate));
+        }
+    }
+    let steps = ScaledSteps::new(300).ordered(fruits.clone());
+    assert_eq!(steps.range_values(), vec![0.0, 299.0]);
+    assert!(steps
+        .tick_values()
+        .iter()
+        .all(|(_, coordinate)| *coordinate <= 299.0));
+    let band = Band::try_new(fruits.clone(), 300).unwrap();
+    let pears = band.map_value(&"Pears").unwrap();
+    assert_eq!(band.invert_value(&(pears + 1.0)), Some("Pears"));
+
+    let colors: Vec<Box<dyn Scale<Domain = f64, Range = Color>>> = vec![
+        Box::new(Sequential::try_new(0.0, 1.0, interpolate_greys).unwrap()),
+        Box::new(Quantize::try_new(0.0, 1.0, [Color::Hex(0xffffff), Color::Hex(0)]).unwrap()),
+        Box::new(Threshold::try_new([0.5], [Color::Hex(0xffffff), Color::Hex(0)]).unwrap()),
+    ];
+    for scale in &colors {
+        assert_eq!(scale.map_value(&0.0).unwrap().to_string(), "#ffffff");
+        assert_eq!(scale.map_value(&1.0).unwrap().to_string(), "#000000");
+        assert!(scale.invert_value(&Color::Hex(0)).is_none());
+    }
+
+    let mut classes = Ordinal::new(fruits.clone(), vec!["odd", "even"]);
+    let ordinal: &dyn Scale<Domain = &str, Range = &str> = &classes;
+    assert_eq!(ordinal.map_value(&"Bananas"), Some("odd"));
+    assert_eq!(ordinal.map_value(&"Cherries"), None);
+    assert_eq!(ordinal.domain_values(), fruits);
+    assert_eq!(ordinal.tick_values().len(), 3);
+    assert_eq!(classes.map("Cherries"), Some(&"even"));
+}
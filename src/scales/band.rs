@@ -1,116 +1,507 @@
-use std::fmt::{Debug, Display};
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    iter::Enumerate,
+};
+
+use indexmap::{set::Iter as IndexSetIter, IndexSet};
+
+use super::{too_small_range, CoordinateRange, Result, Scale, ScaleError};
 
 #[derive(Debug)]
-#[allow(dead_code)]
 pub struct Band<DT>
 where
-    DT: PartialEq + PartialOrd + Debug + Display,
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
 {
     dimension: usize,
-    domain: Vec<DT>,
+    domain: IndexSet<DT>,
     padding_inner: f64,
     padding_outer: f64,
     align: f64,
+    range: CoordinateRange,
 }
 
 pub struct BandIter<'i, DT>
 where
-    DT: PartialEq + PartialOrd + Debug + Display,
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
+{
+    band: &'i Band<DT>,
+    iter: Enumerate<IndexSetIter<'i, DT>>,
+}
+
+pub struct FloatBandIter<'i, DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
 {
-    step: f64,
-    current: f64,
-    bandwidth: usize,
-    iter: std::slice::Iter<'i, DT>,
+    band: &'i Band<DT>,
+    iter: Enumerate<IndexSetIter<'i, DT>>,
+}
+
+/// Fewest coordinates that separate `n` bands by their inner padding
+fn minimum_dimension(n: usize, padding_inner: f64) -> usize {
+    if padding_inner > 0.0 {
+        usize::max(
+            n,
+            f64::round(n.saturating_sub(1) as f64 / padding_inner) as usize,
+        )
+    } else {
+        n
+    }
 }
 
-fn calculate_dimension(n: usize, dimension: usize, padding_inner: f64) -> usize {
-    usize::max(
-        dimension,
-        f64::round((n - 1) as f64 / padding_inner) as usize,
-    )
+/// The values of a categorical domain, in their original order,
+/// or an error when a value occurs more than once
+pub(crate) fn unique_domain<DT, I>(values: I) -> Result<IndexSet<DT>>
+where
+    DT: Display + Hash + Eq,
+    I: IntoIterator<Item = DT>,
+{
+    let mut domain = IndexSet::new();
+    for value in values {
+        if domain.contains(&value) {
+            return Err(ScaleError::InvalidDomain {
+                explain: format!("the value {} occurs more than once", value),
+            });
+        }
+        domain.insert(value);
+    }
+
+    Ok(domain)
+}
+
+/// Distance between the starts of consecutive bands, and the offset of the first band,
+/// when spreading `n` bands over `span` coordinates
+pub(crate) fn spread_bands(
+    n: usize,
+    span: f64,
+    padding_inner: f64,
+    padding_outer: f64,
+    align: f64,
+) -> (f64, f64) {
+    let n = n as f64;
+    // step = (stop - start) / Math.max(1, n - paddingInner + paddingOuter * 2);
+    let step = span / f64::max(1.0, n - padding_inner + padding_outer * 2.0);
+    // start += (stop - start - step * (n - paddingInner)) * align;
+    let offset = (span - step * (n - padding_inner)) * align;
+
+    (step, offset)
 }
 
 impl<DT> Band<DT>
 where
-    DT: PartialEq + PartialOrd + Debug + Display + Default,
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
 {
-    pub fn new<I>(domain: I, dimension: usize) -> Self
+    /// A band for each value of the domain, which must not be empty or contain duplicates
+    ///
+    /// A dimension too small to separate the bands by their inner padding is enlarged.
+    pub fn try_new<I>(domain: I, dimension: usize) -> Result<Self>
     where
         I: IntoIterator<Item = DT>,
     {
-        let domain: Vec<DT> = domain.into_iter().collect();
-        let dimension = calculate_dimension(domain.len(), dimension, 0.1);
+        let domain = unique_domain(domain)?;
+        if domain.is_empty() {
+            return Err(ScaleError::InvalidDomain {
+                explain: "a band scale needs at least one value".to_string(),
+            });
+        }
+        if dimension == 0 {
+            return Err(ScaleError::DimensionTooSmall);
+        }
+        let dimension = usize::max(dimension, minimum_dimension(domain.len(), 0.1));
 
-        Self {
+        Ok(Self {
             dimension,
             domain,
             padding_inner: 0.1,
             padding_outer: 0.05,
             align: 0.5,
+            range: CoordinateRange::new(0, dimension - 1),
+        })
+    }
+
+    /// Place the bands on the coordinates from `start` to `end`, instead of from zero
+    ///
+    /// When the end is smaller than the start, the first band is placed nearest to the start.
+    /// Ranges that are too small to separate the bands by their inner padding are rejected.
+    pub fn range(mut self, start: usize, end: usize) -> Result<Self> {
+        let range = CoordinateRange::new(start, end);
+        let minimum = minimum_dimension(self.domain.len(), self.padding_inner);
+        if range.dimension() < minimum {
+            return Err(too_small_range(range, minimum));
         }
+
+        self.range = range;
+        self.dimension = range.dimension();
+
+        Ok(self)
     }
 
+    /// Space between the bands, as a fraction of the step; values outside `[0, 1)` are ignored
+    ///
+    /// When the range is too small to separate the bands, it is enlarged
+    /// away from its lowest coordinate.
     pub fn padding_inner(mut self, padding: f64) -> Self {
-        self.padding_inner = if padding < 1.0 { padding } else { 0.1 };
-        self.dimension = calculate_dimension(self.domain.len(), self.dimension, self.padding_inner);
+        self.padding_inner = if (0.0..1.0).contains(&padding) {
+            padding
+        } else {
+            0.1
+        };
+
+        let minimum = minimum_dimension(self.domain.len(), self.padding_inner);
+        if self.dimension < minimum {
+            let (start, end) = (self.range.start(), self.range.end());
+            self.range = if self.range.is_reversed() {
+                CoordinateRange::new(end + minimum - 1, end)
+            } else {
+                CoordinateRange::new(start, start + minimum - 1)
+            };
+            self.dimension = minimum;
+        }
 
         self
     }
 
-    pub fn iter<'i>(&'i self) -> BandIter<'i, DT> {
-        let n = self.domain.len() as f64;
-        // step = (stop - start) / Math.max(1, n - paddingInner + paddingOuter * 2);
-        //  let computed_step = n as f32 - self.padding_inner + self.padding_outer * 2f32;
-        let step = self.dimension as f64
-            / f64::max(1.0, n - self.padding_inner + self.padding_outer * 2.0);
-        // start += (stop - start - step * (n - paddingInner)) * align;
-        let current = ((self.dimension - 1) as f64 - step * (n - self.padding_inner)) * self.align;
-        // bandwidth = step * (1 - paddingInner);
-        let bandwidth = usize::max(f64::round(step * (1.0 - self.padding_inner)) as usize, 1);
-        let iter = self.domain.iter();
+    /// Space before the first and after the last band, as a fraction of the step;
+    /// negative paddings are ignored
+    pub fn padding_outer(mut self, padding: f64) -> Self {
+        self.padding_outer = if padding >= 0.0 && padding.is_finite() {
+            padding
+        } else {
+            0.05
+        };
+
+        self
+    }
+
+    /// Distribution of the outer padding, from 0 (all after the last band)
+    /// to 1 (all before the first band); values outside that range are ignored
+    pub fn align(mut self, align: f64) -> Self {
+        self.align = if (0.0..=1.0).contains(&align) {
+            align
+        } else {
+            0.5
+        };
+
+        self
+    }
+
+    /// Exact width of each band; whole bands are rounded, but at least one coordinate wide
+    pub fn bandwidth(&self) -> f64 {
+        self.step() * (1.0 - self.padding_inner)
+    }
+
+    /// Distance between the starts of two consecutive bands
+    pub fn step(&self) -> f64 {
+        self.spread().0
+    }
+
+    /// Start and end coordinate of the band of a domain value
+    pub fn band_of(&self, value: &DT) -> Option<(usize, usize)> {
+        self.domain
+            .get_index_of(value)
+            .map(|index| self.whole_band(index))
+    }
+
+    /// Exact, sub-pixel, start and end of the band of a domain value
+    pub fn float_band_of(&self, value: &DT) -> Option<(f64, f64)> {
+        self.domain
+            .get_index_of(value)
+            .map(|index| self.float_band(index))
+    }
+
+    /// The domain value whose band covers the coordinate, or [None] for the padding
+    pub fn coordinate_to_domain(&self, coordinate: usize) -> Option<&DT> {
+        let offset = self.range.offset(coordinate)?;
+        let (step, first) = self.spread();
+        let index = f64::floor((offset as f64 - first) / step);
+
+        // rounding the start of the next band may have moved it before the coordinate
+        [index, index + 1.0]
+            .into_iter()
+            .filter(|index| *index >= 0.0)
+            .map(|index| index as usize)
+            .find(|index| {
+                *index < self.domain.len() && {
+                    let (start, end) = self.whole_offsets(*index);
+                    start <= offset && offset <= end
+                }
+            })
+            .and_then(|index| self.domain.get_index(index))
+    }
+
+    /// Step and offset of the first band; the bands are spread over all coordinates
+    /// of the dimension, but the last band ends at the coordinate before it
+    fn spread(&self) -> (f64, f64) {
+        let (step, offset) = spread_bands(
+            self.domain.len(),
+            self.dimension as f64,
+            self.padding_inner,
+            self.padding_outer,
+            self.align,
+        );
+
+        (step, offset - self.align)
+    }
+
+    /// Offsets of the first and last whole coordinate of a band, from the start of the range
+    fn whole_offsets(&self, index: usize) -> (usize, usize) {
+        let (step, first) = self.spread();
+        let bandwidth = usize::max(f64::round(self.bandwidth()) as usize, 1);
+        let start = f64::round(first + step * index as f64) as usize;
+
+        (start, start + bandwidth - 1)
+    }
+
+    fn whole_band(&self, index: usize) -> (usize, usize) {
+        let (start, end) = self.whole_offsets(index);
+
+        if self.range.is_reversed() {
+            (
+                self.range.whole_coordinate(end),
+                self.range.whole_coordinate(start),
+            )
+        } else {
+            (
+                self.range.whole_coordinate(start),
+                self.range.whole_coordinate(end),
+            )
+        }
+    }
 
+    fn float_band(&self, index: usize) -> (f64, f64) {
+        let (step, first) = self.spread();
+        let current = first + step * index as f64;
+        let (start, end) = (
+            self.range.coordinate(current),
+            self.range.coordinate(current + self.bandwidth()),
+        );
+
+        (f64::min(start, end), f64::max(start, end))
+    }
+
+    pub fn iter<'i>(&'i self) -> BandIter<'i, DT> {
         BandIter {
-            bandwidth,
-            current,
-            step,
-            iter,
+            band: self,
+            iter: self.domain.iter().enumerate(),
+        }
+    }
+
+    /// Iterate over the exact, sub-pixel, start and end of each band
+    pub fn float_iter<'i>(&'i self) -> FloatBandIter<'i, DT> {
+        FloatBandIter {
+            band: self,
+            iter: self.domain.iter().enumerate(),
         }
     }
 }
 
 impl<'i, DT> Iterator for BandIter<'i, DT>
 where
-    DT: PartialEq + PartialOrd + Debug + Display + Default,
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
 {
     type Item = (&'i DT, (usize, usize));
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(domain) = self.iter.next() {
-            let dimension_start = f64::round(self.current) as usize;
-            let dimension_end = dimension_start + self.bandwidth - 1;
+        self.iter
+            .next()
+            .map(|(index, domain)| (domain, self.band.whole_band(index)))
+    }
+}
 
-            let result = (domain, (dimension_start, dimension_end));
+impl<'i, DT> Iterator for FloatBandIter<'i, DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
+{
+    type Item = (&'i DT, (f64, f64));
 
-            self.current += self.step;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, domain)| (domain, self.band.float_band(index)))
+    }
+}
 
-            Some(result)
+impl<DT> Scale for Band<DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq + Clone,
+{
+    type Domain = DT;
+    type Range = f64;
+
+    fn domain_values(&self) -> Vec<DT> {
+        self.domain.iter().cloned().collect()
+    }
+
+    fn range_values(&self) -> Vec<f64> {
+        vec![self.range.start() as f64, self.range.end() as f64]
+    }
+
+    /// The exact start of the band of the value, the side nearest to coordinate zero
+    fn map_value(&self, value: &DT) -> Option<f64> {
+        self.float_band_of(value).map(|(start, _)| start)
+    }
+
+    /// The domain value whose band covers the coordinate
+    fn invert_value(&self, coordinate: &f64) -> Option<DT> {
+        if *coordinate >= 0.0 {
+            self.coordinate_to_domain(f64::round(*coordinate) as usize)
+                .cloned()
         } else {
             None
         }
     }
+
+    fn tick_values(&self) -> Vec<(DT, f64)> {
+        self.float_iter()
+            .map(|(value, (start, _))| (value.clone(), start))
+            .collect()
+    }
 }
 
 #[test]
 fn create_band() {
-    let band = Band::new(1977..2018, 600).padding_inner(0.1);
+    let band = Band::try_new(1977..2018, 600).unwrap().padding_inner(0.1);
 
     for (domain, (start, end)) in band.iter() {
         println!("domain: {} -> ({}, {})", domain, start, end)
     }
 
-    let band = Band::new(vec!["Apples", "Pears", "Bananas"], 300);
+    let band = Band::try_new(vec!["Apples", "Pears", "Bananas"], 300).unwrap();
 
     for (domain, (start, end)) in band.iter() {
         println!("domain: {} -> ({}, {})", domain, start, end)
     }
 }
+
+#[test]
+fn float_band() {
+    let band = Band::try_new(vec!["Apples", "Pears", "Bananas"], 301)
+        .unwrap()
+        .padding_inner(0.2);
+
+    let mut bands = band.float_iter();
+    let (domain, (start, end)) = bands.next().unwrap();
+    assert_eq!(domain, &"Apples");
+    assert!((end - start - 0.8 * 301.0 / 2.9).abs() < 1e-9);
+
+    let (_, (next_start, _)) = bands.next().unwrap();
+    assert!((next_start - start - 301.0 / 2.9).abs() < 1e-9);
+
+    for ((_, (start, _)), (_, (float_start, _))) in band.iter().zip(band.float_iter()) {
+        assert_eq!(start, f64::round(float_start) as usize);
+    }
+}
+
+#[test]
+fn band_range() {
+    let band = Band::try_new(vec!["Apples", "Pears", "Bananas"], 300).unwrap();
+    let shifted = Band::try_new(vec!["Apples", "Pears", "Bananas"], 300)
+        .unwrap()
+        .range(50, 349)
+        .unwrap();
+    for ((_, (start, end)), (_, (shifted_start, shifted_end))) in band.iter().zip(shifted.iter()) {
+        assert_eq!((start + 50, end + 50), (shifted_start, shifted_end));
+    }
+
+    let upwards = Band::try_new(vec!["Apples", "Pears", "Bananas"], 300)
+        .unwrap()
+        .range(349, 50)
+        .unwrap();
+    let (domain, (start, end)) = upwards.iter().next().unwrap();
+    let (_, (first_start, first_end)) = band.iter().next().unwrap();
+    assert_eq!(domain, &"Apples");
+    assert_eq!((start, end), (349 - first_end, 349 - first_start));
+
+    let (_, (float_start, float_end)) = upwards.float_iter().next().unwrap();
+    assert!(float_start < float_end);
+    assert!(float_end <= 349.0);
+
+    assert!(Band::try_new(vec!["Apples", "Pears", "Bananas"], 300)
+        .unwrap()
+        .range(10, 12)
+        .is_err());
+}
+
+#[test]
+fn band_rejects_duplicates() {
+    assert!(Band::try_new(vec!["Apples", "Pears", "Apples"], 300).is_err());
+}
+
+#[test]
+fn band_rejects_empty() {
+    assert!(matches!(
+        Band::<&str>::try_new(vec![], 300),
+        Err(ScaleError::InvalidDomain { .. })
+    ));
+    assert!(matches!(
+        Band::try_new(vec!["Apples"], 0),
+        Err(ScaleError::DimensionTooSmall)
+    ));
+
+    let band = Band::try_new(vec!["Apples"], 1).unwrap();
+    assert_eq!(band.band_of(&"Apples"), Some((0, 0)));
+}
+
+#[test]
+fn band_padding_inner() {
+    let band = Band::try_new(vec!["Apples", "Pears", "Bananas"], 300)
+        .unwrap()
+        .padding_inner(0.0);
+    assert!((band.step() - 300.0 / 3.1).abs() < 1e-9);
+    assert_eq!(band.bandwidth(), band.step());
+
+    // negative and NaN paddings fall back to the default
+    for padding in [-0.5, f64::NAN, 1.0] {
+        let band = Band::try_new(vec!["Apples", "Pears", "Bananas"], 300)
+            .unwrap()
+            .padding_inner(padding);
+        assert!((band.bandwidth() - 0.9 * band.step()).abs() < 1e-9);
+    }
+
+    // a larger padding needs more coordinates; the range grows with the dimension
+    let band = Band::try_new(vec!["Apples", "Pears", "Bananas"], 20)
+        .unwrap()
+        .range(50, 69)
+        .unwrap()
+        .padding_inner(0.05);
+    assert_eq!(band.range_values(), vec![50.0, 89.0]);
+    let (_, last) = band.band_of(&"Bananas").unwrap();
+    assert!(last <= 89);
+}
+
+#[test]
+fn band_lookup() {
+    let band = Band::try_new(vec!["Apples", "Pears", "Bananas"], 300)
+        .unwrap()
+        .padding_inner(0.2)
+        .padding_outer(0.5)
+        .align(0.0);
+
+    assert!((band.step() - 300.0 / 3.8).abs() < 1e-9);
+    assert!((band.bandwidth() - 0.8 * 300.0 / 3.8).abs() < 1e-9);
+
+    for (domain, whole) in band.iter() {
+        assert_eq!(band.band_of(domain), Some(whole));
+        assert_eq!(band.coordinate_to_domain(whole.0), Some(domain));
+        assert_eq!(band.coordinate_to_domain(whole.1), Some(domain));
+    }
+    for (domain, float) in band.float_iter() {
+        assert_eq!(band.float_band_of(domain), Some(float));
+    }
+    assert_eq!(band.band_of(&"Cherries"), None);
+
+    // align 0 puts all outer padding after the last band
+    assert_eq!(band.band_of(&"Apples").map(|(start, _)| start), Some(0));
+    let (_, last) = band.band_of(&"Bananas").unwrap();
+    assert_eq!(band.coordinate_to_domain(last + 1), None);
+    assert_eq!(band.coordinate_to_domain(299), None);
+
+    let upwards = Band::try_new(vec!["Apples", "Pears", "Bananas"], 300)
+        .unwrap()
+        .range(349, 50)
+        .unwrap();
+    let (start, end) = upwards.band_of(&"Apples").unwrap();
+    assert_eq!(upwards.coordinate_to_domain(start), Some(&"Apples"));
+    assert_eq!(upwards.coordinate_to_domain(end), Some(&"Apples"));
+    assert_eq!(upwards.coordinate_to_domain(350), None);
+}
@@ -0,0 +1,287 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{AddAssign, Sub},
+};
+
+use super::{
+    too_small_range, ConvertToFloat, CoordinateRange, DomainScale, IterableScale, Linear, Result,
+    Scale, ScaleError,
+};
+
+/// The transformation a [Continuous] scale applies to its domain before mapping it linearly
+pub trait Transformation: Debug + Default {
+    /// Whether the transformation is only defined for values larger than zero
+    const STRICTLY_POSITIVE: bool = false;
+
+    fn transform(&self, value: f64) -> f64;
+    fn untransform(&self, value: f64) -> f64;
+
+    /// Domain values from `start` to `end` at intervals of `step`, in the order of the domain
+    fn intervals(&self, start: f64, end: f64, step: f64) -> Vec<f64>;
+
+    /// Representative domain values from `start` to `end`, in the order of the domain
+    fn ticks(&self, start: f64, end: f64) -> Vec<f64>;
+}
+
+/// Continuous scale, mapping a domain onto a dimension after applying a [Transformation]
+///
+/// Used through the [Pow](super::Pow), [Log](super::Log) and [SymLog](super::SymLog) scales.
+#[derive(Debug)]
+pub struct Continuous<DT, T>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT>,
+{
+    start: DT,
+    end: DT,
+    dimension: usize,
+    min: DT,
+    max: DT,
+    range: CoordinateRange,
+    transformation: T,
+}
+
+pub type ContinuousIter<DT> = std::vec::IntoIter<(DT, usize)>;
+
+impl<DT, T> Continuous<DT, T>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    T: Transformation,
+{
+    pub fn try_new(start: DT, end: DT, dimension: usize) -> Result<Self> {
+        if dimension < 5 {
+            return Err(ScaleError::DimensionTooSmall);
+        }
+
+        let (min, max) = if start < end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        if T::STRICTLY_POSITIVE && min <= <Linear<DT> as ConvertToFloat<DT>>::ZERO {
+            return Err(ScaleError::OutOfRange {
+                explain: format!(
+                    "minimum value {} is out of range; the domain must be larger than {}",
+                    min,
+                    <Linear<DT> as ConvertToFloat<DT>>::ZERO
+                ),
+            });
+        }
+        if min < <Linear<DT> as ConvertToFloat<DT>>::MIN {
+            return Err(ScaleError::OutOfRange {
+                explain: format!(
+                    "minimum value {} is out of range; must be larger than {}",
+                    min,
+                    <Linear<DT> as ConvertToFloat<DT>>::MIN
+                ),
+            });
+        }
+        if max > <Linear<DT> as ConvertToFloat<DT>>::MAX {
+            return Err(ScaleError::OutOfRange {
+                explain: format!(
+                    "maximum value {} is out of range; must be less than {}",
+                    max,
+                    <Linear<DT> as ConvertToFloat<DT>>::MAX
+                ),
+            });
+        }
+
+        Ok(Self {
+            start,
+            end,
+            dimension,
+            min,
+            max,
+            range: CoordinateRange::new(0, dimension - 1),
+            transformation: T::default(),
+        })
+    }
+
+    /// Map the domain onto the coordinates from `start` to `end`, instead of from zero
+    ///
+    /// The end may be smaller than the start, which reverses the direction of the coordinates.
+    /// Ranges with less than 5 coordinates are rejected.
+    pub fn range(mut self, start: usize, end: usize) -> Result<Self> {
+        let range = CoordinateRange::new(start, end);
+        if range.dimension() < 5 {
+            return Err(too_small_range(range, 5));
+        }
+
+        self.range = range;
+        self.dimension = range.dimension();
+
+        Ok(self)
+    }
+
+    pub(super) fn with_transformation(mut self, transformation: T) -> Self {
+        self.transformation = transformation;
+
+        self
+    }
+
+    fn to_float(value: DT) -> f64 {
+        <Linear<DT> as ConvertToFloat<DT>>::to_float(value)
+    }
+
+    fn from_float(value: f64) -> DT {
+        <Linear<DT> as ConvertToFloat<DT>>::from_float(value)
+    }
+
+    /// The transformed start of the domain, and the transformed length of the domain
+    fn transformed_domain(&self) -> (f64, f64) {
+        let transformed_start = self.transformation.transform(Self::to_float(self.start));
+        let transformed_end = self.transformation.transform(Self::to_float(self.end));
+
+        (transformed_start, transformed_end - transformed_start)
+    }
+
+    fn float_coordinate(&self, value: f64) -> f64 {
+        let (transformed_start, transformed_range) = self.transformed_domain();
+
+        self.range.coordinate(if transformed_range == 0.0 {
+            0.0
+        } else {
+            (self.transformation.transform(value) - transformed_start) / transformed_range
+                * (self.dimension - 1) as f64
+        })
+    }
+
+    fn coordinate(&self, value: f64) -> usize {
+        f64::round(self.float_coordinate(value)) as usize
+    }
+
+    /// The domain value at an exact offset from the start of the range
+    fn offset_to_domain(&self, offset: f64) -> DT {
+        let (transformed_start, transformed_range) = self.transformed_domain();
+        let transformed =
+            transformed_start + transformed_range * offset / (self.dimension - 1) as f64;
+
+        Self::from_float(self.transformation.untransform(transformed))
+    }
+}
+
+impl<DT, T> DomainScale<DT> for Continuous<DT, T>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    T: Transformation,
+{
+    fn domain_to_float_coordinate(&self, value: DT) -> Option<f64> {
+        if self.min <= value && value <= self.max {
+            Some(self.float_coordinate(Self::to_float(value)))
+        } else {
+            None
+        }
+    }
+
+    fn coordinate_to_domain(&self, coordinate: usize) -> Option<DT> {
+        self.range
+            .offset(coordinate)
+            .filter(|offset| *offset < self.dimension)
+            .map(|offset| self.offset_to_domain(offset as f64))
+    }
+}
+
+impl<DT, T> IterableScale<DT> for Continuous<DT, T>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    T: Transformation,
+{
+    type Iter = ContinuousIter<DT>;
+
+    /// Every coordinate of the dimension together with its domain value
+    fn iter(&self) -> Self::Iter {
+        (0..self.dimension)
+            .map(|offset| {
+                (
+                    self.offset_to_domain(offset as f64),
+                    self.range.whole_coordinate(offset),
+                )
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Domain values at intervals of `step`, as defined by the [Transformation]
+    fn intervals(&self, step: DT) -> Self::Iter {
+        self.transformation
+            .intervals(
+                Self::to_float(self.start),
+                Self::to_float(self.end),
+                Self::to_float(step),
+            )
+            .into_iter()
+            .map(|value| (Self::from_float(value), self.coordinate(value)))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<DT, T> Scale for Continuous<DT, T>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    T: Transformation,
+{
+    type Domain = DT;
+    type Range = f64;
+
+    fn domain_values(&self) -> Vec<DT> {
+        vec![self.start, self.end]
+    }
+
+    fn range_values(&self) -> Vec<f64> {
+        vec![self.range.start() as f64, self.range.end() as f64]
+    }
+
+    fn map_value(&self, value: &DT) -> Option<f64> {
+        self.domain_to_float_coordinate(*value)
+    }
+
+    fn invert_value(&self, coordinate: &f64) -> Option<DT> {
+        let offset = self.range.float_offset(*coordinate);
+        if 0.0 <= offset && offset <= (self.dimension - 1) as f64 {
+            Some(self.offset_to_domain(offset))
+        } else {
+            None
+        }
+    }
+
+    fn tick_values(&self) -> Vec<(DT, f64)> {
+        self.transformation
+            .ticks(Self::to_float(self.start), Self::to_float(self.end))
+            .into_iter()
+            .map(|value| {
+                let value = Self::from_float(value);
+                (value, self.float_coordinate(Self::to_float(value)))
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn continuous_range() {
+    use super::{Log, Pow};
+
+    let y_axis = Log::try_new(1.0_f64, 100.0, 10)
+        .unwrap()
+        .range(220, 20)
+        .unwrap();
+    assert_eq!(y_axis.domain_to_coordinate(1.0), Some(220));
+    assert_eq!(y_axis.domain_to_coordinate(10.0), Some(120));
+    assert_eq!(y_axis.domain_to_coordinate(100.0), Some(20));
+    assert_eq!(y_axis.coordinate_to_domain(120), Some(10.0));
+    assert_eq!(y_axis.coordinate_to_domain(19), None);
+    assert_eq!(y_axis.invert_value(&70.0).map(f64::round), Some(32.0));
+    assert_eq!(y_axis.invert_value(&221.0), None);
+    assert_eq!(y_axis.range_values(), vec![220.0, 20.0]);
+
+    let ticks: Vec<(f64, usize)> = y_axis.intervals(0.0).collect();
+    assert_eq!(ticks, vec![(1.0, 220), (10.0, 120), (100.0, 20)]);
+
+    assert!(Pow::try_sqrt(0.0_f64, 1.0, 10)
+        .unwrap()
+        .range(3, 0)
+        .is_err());
+}
@@ -0,0 +1,160 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{AddAssign, Sub},
+};
+
+use crate::Color;
+
+use super::{color_ticks, ConvertToFloat, Linear, Result, Scale, ScaleError};
+
+/// Diverging colour scale, mapping a continuous domain with a meaningful midpoint
+/// through an interpolator onto colours
+///
+/// The part of the domain before the midpoint maps onto the first half of the unit interval,
+/// the part after it onto the second half, so the midpoint always gets the central colour
+/// of schemes like [interpolate_rd_bu](super::interpolate_rd_bu).
+/// Values outside of the domain are clamped.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::{interpolate_rd_bu, Diverging};
+///
+/// let anomaly = Diverging::try_new(-2.0, 0.0, 6.0, interpolate_rd_bu).unwrap();
+///
+/// assert_eq!(anomaly.map(-2.0).unwrap().to_string(), "#67001f");
+/// assert_eq!(anomaly.map(6.0).unwrap().to_string(), "#053061");
+/// ```
+pub struct Diverging<DT, I>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT>,
+    I: Fn(f64) -> Color,
+{
+    start: DT,
+    midpoint: DT,
+    end: DT,
+    interpolator: I,
+}
+
+impl<DT, I> Diverging<DT, I>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    I: Fn(f64) -> Color,
+{
+    /// The midpoint must lie between the start and the end, which may be reversed
+    pub fn try_new(start: DT, midpoint: DT, end: DT, interpolator: I) -> Result<Self> {
+        for value in [start, midpoint, end] {
+            if value < <Linear<DT> as ConvertToFloat<DT>>::MIN
+                || value > <Linear<DT> as ConvertToFloat<DT>>::MAX
+            {
+                return Err(ScaleError::OutOfRange {
+                    explain: format!(
+                        "domain value {} is out of range; must be between {} and {}",
+                        value,
+                        <Linear<DT> as ConvertToFloat<DT>>::MIN,
+                        <Linear<DT> as ConvertToFloat<DT>>::MAX
+                    ),
+                });
+            }
+        }
+        if !(start <= midpoint && midpoint <= end || end <= midpoint && midpoint <= start) {
+            return Err(ScaleError::InvalidDomain {
+                explain: format!(
+                    "midpoint {} does not lie between {} and {}",
+                    midpoint, start, end
+                ),
+            });
+        }
+
+        Ok(Self {
+            start,
+            midpoint,
+            end,
+            interpolator,
+        })
+    }
+
+    fn to_float(value: DT) -> f64 {
+        <Linear<DT> as ConvertToFloat<DT>>::to_float(value)
+    }
+
+    /// The colour of a value, or [None] when the value is not a number
+    pub fn map(&self, value: DT) -> Option<Color> {
+        let (start, midpoint, end) = (
+            Self::to_float(self.start),
+            Self::to_float(self.midpoint),
+            Self::to_float(self.end),
+        );
+        let value = Self::to_float(value);
+
+        if value.is_nan() {
+            None
+        } else {
+            let before_midpoint = if start < end {
+                value < midpoint
+            } else {
+                value > midpoint
+            };
+            let half = if before_midpoint {
+                if start == midpoint {
+                    0.0
+                } else {
+                    (value - midpoint) / (midpoint - start)
+                }
+            } else if midpoint == end {
+                0.0
+            } else {
+                (value - midpoint) / (end - midpoint)
+            };
+
+            Some((self.interpolator)((0.5 + half * 0.5).clamp(0.0, 1.0)))
+        }
+    }
+}
+
+impl<DT, I> Scale for Diverging<DT, I>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    I: Fn(f64) -> Color,
+{
+    type Domain = DT;
+    type Range = Color;
+
+    fn domain_values(&self) -> Vec<DT> {
+        vec![self.start, self.midpoint, self.end]
+    }
+
+    fn range_values(&self) -> Vec<Color> {
+        vec![
+            (self.interpolator)(0.0),
+            (self.interpolator)(0.5),
+            (self.interpolator)(1.0),
+        ]
+    }
+
+    fn map_value(&self, value: &DT) -> Option<Color> {
+        self.map(*value)
+    }
+
+    /// Round values, spaced like the ticks of a [Linear] scale, together with their colours
+    fn tick_values(&self) -> Vec<(DT, Color)> {
+        color_ticks(self.start, self.end, |value| self.map(value))
+    }
+}
+
+#[test]
+fn diverging_colors() {
+    use super::interpolate_pu_or;
+
+    let diverging = Diverging::try_new(100_i32, 0, -50, interpolate_pu_or).unwrap();
+    let color = |value| diverging.map(value).unwrap().to_string();
+
+    assert_eq!(color(0), interpolate_pu_or(0.5).to_string());
+    assert_eq!(color(50), interpolate_pu_or(0.25).to_string());
+    assert_eq!(color(-25), interpolate_pu_or(0.75).to_string());
+    assert_eq!(color(1_000), "#7f3b08");
+    assert_eq!(color(-1_000), "#2d004b");
+
+    assert!(Diverging::try_new(0.0, 2.0, 1.0, interpolate_pu_or).is_err());
+}
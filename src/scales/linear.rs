@@ -3,15 +3,10 @@ use std::{
     ops::{Add, AddAssign, Sub},
 };
 
-use super::{DomainScale, Result, ScaleError};
-
-pub trait IterableScale<DT>
-where
-    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
-{
-    fn iter(&self) -> DomainIter<DT>;
-    fn intervals(&self, step: DT) -> DomainIter<DT>;
-}
+use super::{
+    too_small_range, Continuous, ContinuousIter, CoordinateRange, DomainScale, IterableScale,
+    OutOfDomain, Result, Scale, ScaleError, Transformation,
+};
 
 #[derive(Debug)]
 pub struct Linear<DT>
@@ -24,6 +19,8 @@ where
     max: DT,
     ratio: f64,
     domain_range: f64,
+    range: CoordinateRange,
+    out_of_domain: OutOfDomain,
 }
 
 #[derive(Debug)]
@@ -36,6 +33,7 @@ where
     increment: f64,
     dimension: f64,
     dimension_end: usize,
+    range: CoordinateRange,
     from_float: fn(f64) -> DT,
 }
 
@@ -105,9 +103,92 @@ where
             max,
             ratio,
             domain_range,
+            range: CoordinateRange::new(0, dimension - 1),
+            out_of_domain: OutOfDomain::default(),
         })
     }
 
+    /// Pin values outside of the domain to the edges of the range
+    pub fn clamp(mut self) -> Self {
+        self.out_of_domain = OutOfDomain::Clamp;
+
+        self
+    }
+
+    /// Map values outside of the domain to coordinates outside of the range
+    ///
+    /// The exact coordinates may be negative, see [DomainScale::domain_to_float_coordinate].
+    pub fn extrapolate(mut self) -> Self {
+        self.out_of_domain = OutOfDomain::Extrapolate;
+
+        self
+    }
+
+    fn out_of_domain_to_coordinate(&self, value: DT) -> Option<f64> {
+        if self.out_of_domain == OutOfDomain::Discard || self.ratio == 0.0 {
+            return None;
+        }
+
+        let offset = (Self::to_float(value) - Self::to_float(self.start)) / self.ratio;
+        let offset = if self.out_of_domain == OutOfDomain::Clamp {
+            offset.clamp(0.0, (self.dimension - 1) as f64)
+        } else {
+            offset
+        };
+
+        Some(self.range.coordinate(offset))
+    }
+
+    fn out_of_range_to_domain(&self, coordinate: usize) -> Option<DT> {
+        let offset = self.range.float_offset(coordinate as f64);
+        let offset = match self.out_of_domain {
+            OutOfDomain::Discard => return None,
+            OutOfDomain::Clamp => offset.clamp(0.0, (self.dimension - 1) as f64),
+            OutOfDomain::Extrapolate => offset,
+        };
+
+        Some(Self::from_float(
+            Self::to_float(self.start) + self.ratio * offset,
+        ))
+    }
+
+    /// The domain value of an exact coordinate, which may lie outside of the range
+    fn float_coordinate_to_domain(&self, coordinate: f64) -> Option<DT> {
+        let offset = self.range.float_offset(coordinate);
+        let last = (self.dimension - 1) as f64;
+        let offset = if (0.0..=last).contains(&offset) {
+            offset
+        } else {
+            match self.out_of_domain {
+                OutOfDomain::Discard => return None,
+                OutOfDomain::Clamp => offset.clamp(0.0, last),
+                OutOfDomain::Extrapolate => offset,
+            }
+        };
+
+        Some(Self::from_float(
+            Self::to_float(self.start) + self.ratio * offset,
+        ))
+    }
+
+    /// Map the domain onto the coordinates from `start` to `end`, instead of from zero
+    ///
+    /// The end may be smaller than the start, which reverses the direction of the coordinates.
+    /// Ranges with less than 5 coordinates are rejected.
+    pub fn range(mut self, start: usize, end: usize) -> Result<Self> {
+        let range = CoordinateRange::new(start, end);
+        let dimension = range.dimension();
+        if dimension < 5 {
+            return Err(too_small_range(range, 5));
+        }
+
+        self.ratio = self.domain_range / (dimension - 1) as f64 * f64::signum(self.ratio);
+        self.dimension = dimension;
+        self.range = range;
+
+        Ok(self)
+    }
+
     fn create_iter(&self, step: f64) -> DomainIter<DT> {
         let increment = f64::abs(step / self.ratio);
 
@@ -118,9 +199,166 @@ where
             increment,
             dimension: 0.0,
             dimension_end: self.dimension,
+            range: self.range,
             from_float: <Self as ConvertToFloat<DT>>::from_float,
         }
     }
+
+    /// Extend the domain so that it starts and ends at round values
+    ///
+    /// When the extended domain would be out of range, the scale is returned unchanged.
+    pub fn nice(self) -> Self {
+        let reversed = self.start != self.min;
+        let mut start = Self::to_float(self.min);
+        let mut stop = Self::to_float(self.max);
+        let mut previous_step = None;
+
+        for _ in 0..10 {
+            let step = tick_increment(start, stop, DEFAULT_TICK_COUNT);
+            if previous_step == Some(step) {
+                break;
+            } else if step > 0.0 {
+                start = f64::floor(start / step) * step;
+                stop = f64::ceil(stop / step) * step;
+            } else if step < 0.0 {
+                start = f64::ceil(start * step) / step;
+                stop = f64::floor(stop * step) / step;
+            } else {
+                break;
+            }
+            previous_step = Some(step);
+        }
+
+        if start < Self::to_float(<Self as ConvertToFloat<DT>>::MIN)
+            || stop > Self::to_float(<Self as ConvertToFloat<DT>>::MAX)
+        {
+            return self;
+        }
+
+        let (start, stop) = (Self::from_float(start), Self::from_float(stop));
+        let nice = if reversed {
+            Self::try_new(stop, start, self.dimension)
+        } else {
+            Self::try_new(start, stop, self.dimension)
+        };
+
+        match nice {
+            Ok(mut nice) => {
+                nice.out_of_domain = self.out_of_domain;
+                match nice.range(self.range.start(), self.range.end()) {
+                    Ok(nice) => nice,
+                    Err(_) => self,
+                }
+            }
+            Err(_) => self,
+        }
+    }
+
+    /// Approximately `count` ticks at round values within the domain, together with their coordinates
+    ///
+    /// The ticks are spaced at 1, 2 or 5 times a power of ten, following d3's tickIncrement.
+    pub fn ticks(&self, count: usize) -> TickIter<DT> {
+        let start = Self::to_float(self.start);
+        let (min, max) = (Self::to_float(self.min), Self::to_float(self.max));
+
+        let mut ticks = Vec::new();
+        if count > 0 && min == max {
+            ticks.push((self.start, self.range.start()));
+        } else {
+            for value in tick_values(min, max, count) {
+                let coordinate = f64::round(self.range.coordinate(
+                    f64::abs(value - start) / self.domain_range * (self.dimension - 1) as f64,
+                )) as usize;
+                ticks.push((Self::from_float(value), coordinate));
+            }
+        }
+
+        if self.start != self.min {
+            ticks.reverse();
+        }
+
+        ticks.into_iter()
+    }
+}
+
+pub type TickIter<DT> = std::vec::IntoIter<(DT, usize)>;
+
+pub(crate) const DEFAULT_TICK_COUNT: usize = 10;
+
+/// The step between about `count` round values from `start` to `stop`
+///
+/// A positive result is the step itself, a negative result is the inverse of
+/// a step below one (e.g. -10 for a step of 0.1), which avoids rounding errors.
+pub fn tick_increment(start: f64, stop: f64, count: usize) -> f64 {
+    tick_spec(start, stop, count as f64).map_or(f64::NAN, |(_, _, increment)| increment)
+}
+
+/// About `count` round values from `min` to `max`, ascending, like the ticks of a [Linear] scale
+pub(crate) fn tick_values(min: f64, max: f64, count: usize) -> Vec<f64> {
+    if count == 0 {
+        Vec::new()
+    } else if min == max {
+        vec![min]
+    } else {
+        tick_spec(min, max, count as f64).map_or_else(Vec::new, |(first, last, increment)| {
+            (first..=last)
+                .map(|index| {
+                    if increment < 0.0 {
+                        index as f64 / -increment
+                    } else {
+                        index as f64 * increment
+                    }
+                })
+                .collect()
+        })
+    }
+}
+
+fn tick_spec(start: f64, stop: f64, count: f64) -> Option<(i64, i64, f64)> {
+    let step = (stop - start) / f64::max(0.0, count);
+    let power = f64::floor(step.log10());
+    let error = step / 10_f64.powf(power);
+    let factor = if error >= f64::sqrt(50.0) {
+        10.0
+    } else if error >= f64::sqrt(10.0) {
+        5.0
+    } else if error >= f64::sqrt(2.0) {
+        2.0
+    } else {
+        1.0
+    };
+
+    let (first, last, increment) = if power < 0.0 {
+        let increment = 10_f64.powf(-power) / factor;
+        let mut first = f64::round(start * increment);
+        let mut last = f64::round(stop * increment);
+        if first / increment < start {
+            first += 1.0;
+        }
+        if last / increment > stop {
+            last -= 1.0;
+        }
+        (first, last, -increment)
+    } else {
+        let increment = 10_f64.powf(power) * factor;
+        let mut first = f64::round(start / increment);
+        let mut last = f64::round(stop / increment);
+        if first * increment < start {
+            first += 1.0;
+        }
+        if last * increment > stop {
+            last -= 1.0;
+        }
+        (first, last, increment)
+    };
+
+    if !increment.is_finite() {
+        None
+    } else if last < first && (0.5..2.0).contains(&count) {
+        tick_spec(start, stop, count * 2.0)
+    } else {
+        Some((first as i64, last as i64, increment))
+    }
 }
 
 impl<DT> Iterator for DomainIter<DT>
@@ -132,7 +370,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let dimension = f64::round(self.dimension) as usize;
         if dimension < self.dimension_end {
-            let result = ((self.from_float)(self.current), dimension);
+            let result = (
+                (self.from_float)(self.current),
+                self.range.whole_coordinate(dimension),
+            );
             self.dimension += self.increment;
             self.current += self.domain_step;
             Some(result)
@@ -173,34 +414,37 @@ macro_rules! implement_numerical_traits {
         }
 
         impl DomainScale<$typ> for Linear<$typ> {
-            fn domain_to_coordinate(&self, value: $typ) -> Option<usize> {
+            fn domain_to_float_coordinate(&self, value: $typ) -> Option<f64> {
                 if self.min <= value && value <= self.max {
                     let diff = (if value < self.start {
                         self.start - value
                     } else {
                         value - self.start
                     }) as f64;
-                    let dimension =
-                        f64::round((diff / self.domain_range) * (self.dimension - 1) as f64)
-                            as usize;
 
-                    Some(dimension)
+                    Some(
+                        self.range
+                            .coordinate((diff / self.domain_range) * (self.dimension - 1) as f64),
+                    )
                 } else {
-                    None
+                    self.out_of_domain_to_coordinate(value)
                 }
             }
 
             fn coordinate_to_domain(&self, coordinate: usize) -> Option<$typ> {
-                if coordinate < self.dimension {
-                    let domain_value = self.ratio * (coordinate as f64);
-                    Some(self.start + domain_value as $typ)
-                } else {
-                    None
+                match self.range.offset(coordinate) {
+                    Some(offset) if offset < self.dimension => {
+                        let domain_value = self.ratio * (offset as f64);
+                        Some(self.start + domain_value as $typ)
+                    }
+                    _ => self.out_of_range_to_domain(coordinate),
                 }
             }
         }
 
         impl IterableScale<$typ> for Linear<$typ> {
+            type Iter = DomainIter<$typ>;
+
             fn iter(&self) -> DomainIter<$typ> {
                 self.create_iter(self.ratio)
             }
@@ -209,6 +453,40 @@ macro_rules! implement_numerical_traits {
                 self.create_iter(<Self as ConvertToFloat<$typ>>::to_float(step))
             }
         }
+
+        impl Scale for Linear<$typ> {
+            type Domain = $typ;
+            type Range = f64;
+
+            fn domain_values(&self) -> Vec<$typ> {
+                if self.start == self.min {
+                    vec![self.min, self.max]
+                } else {
+                    vec![self.max, self.min]
+                }
+            }
+
+            fn range_values(&self) -> Vec<f64> {
+                vec![self.range.start() as f64, self.range.end() as f64]
+            }
+
+            fn map_value(&self, value: &$typ) -> Option<f64> {
+                self.domain_to_float_coordinate(*value)
+            }
+
+            fn invert_value(&self, coordinate: &f64) -> Option<$typ> {
+                self.float_coordinate_to_domain(*coordinate)
+            }
+
+            fn tick_values(&self) -> Vec<($typ, f64)> {
+                self.ticks(DEFAULT_TICK_COUNT)
+                    .filter_map(|(value, _)| {
+                        self.domain_to_float_coordinate(value)
+                            .map(|coordinate| (value, coordinate))
+                    })
+                    .collect()
+            }
+        }
     };
 }
 
@@ -231,6 +509,118 @@ implement_numerical_traits!(u16, 1, u16::MIN, u16::MAX - 1);
 implement_numerical_traits!(f32, 0.0, f32::MIN, f32::MAX);
 implement_numerical_traits!(f64, 0.0, f64::MIN, f64::MAX);
 
+/// Power scale, applying an exponent to the domain before it is mapped linearly
+///
+/// Negative domain values are transformed symmetrically, i.e. `-|x|^k`.
+pub type Pow<DT> = Continuous<DT, Power>;
+
+/// Square root scale, a power scale with an exponent of 0.5
+pub type Sqrt<DT> = Continuous<DT, SquareRoot>;
+
+pub type PowIter<DT> = ContinuousIter<DT>;
+
+/// The transformation of a [Pow] scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Power {
+    exponent: f64,
+}
+
+impl Default for Power {
+    fn default() -> Self {
+        Self { exponent: 1.0 }
+    }
+}
+
+impl Transformation for Power {
+    fn transform(&self, value: f64) -> f64 {
+        value.signum() * value.abs().powf(self.exponent)
+    }
+
+    fn untransform(&self, value: f64) -> f64 {
+        value.signum() * value.abs().powf(1.0 / self.exponent)
+    }
+
+    /// Values at regular steps from the start of the domain
+    fn intervals(&self, start: f64, end: f64, step: f64) -> Vec<f64> {
+        let step = f64::abs(step);
+
+        let mut values = Vec::new();
+        if step > 0.0 {
+            let sign = if start < end { 1.0 } else { -1.0 };
+            let count = f64::floor(f64::abs(end - start) / step) as usize;
+            for index in 0..=count {
+                values.push(start + sign * step * index as f64);
+            }
+        }
+
+        values
+    }
+
+    /// Round values, spaced like the ticks of a [Linear] scale
+    fn ticks(&self, start: f64, end: f64) -> Vec<f64> {
+        let mut values = tick_values(
+            f64::min(start, end),
+            f64::max(start, end),
+            DEFAULT_TICK_COUNT,
+        );
+        if start > end {
+            values.reverse();
+        }
+
+        values
+    }
+}
+
+/// The transformation of a [Sqrt] scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SquareRoot(Power);
+
+impl Default for SquareRoot {
+    fn default() -> Self {
+        Self(Power { exponent: 0.5 })
+    }
+}
+
+impl Transformation for SquareRoot {
+    fn transform(&self, value: f64) -> f64 {
+        self.0.transform(value)
+    }
+
+    fn untransform(&self, value: f64) -> f64 {
+        self.0.untransform(value)
+    }
+
+    fn intervals(&self, start: f64, end: f64, step: f64) -> Vec<f64> {
+        self.0.intervals(start, end, step)
+    }
+
+    fn ticks(&self, start: f64, end: f64) -> Vec<f64> {
+        self.0.ticks(start, end)
+    }
+}
+
+impl<DT> Pow<DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+{
+    /// Same as [Sqrt::try_new]
+    pub fn try_sqrt(start: DT, end: DT, dimension: usize) -> Result<Sqrt<DT>> {
+        Sqrt::try_new(start, end, dimension)
+    }
+
+    /// Set the exponent, which must be finite and not zero
+    pub fn exponent(self, exponent: f64) -> Result<Self> {
+        if exponent == 0.0 || !exponent.is_finite() {
+            return Err(ScaleError::InvalidParameter {
+                explain: format!("exponent {} must be finite and not zero", exponent),
+            });
+        }
+
+        Ok(self.with_transformation(Power { exponent }))
+    }
+}
+
 #[cfg(test)]
 fn show_result<DT>(scale: Result<Linear<DT>>)
 where
@@ -269,3 +659,152 @@ fn intervals() {
         println!("Tick={:?}, sin(x)={}", tick, f64::sin(tick.1 .0));
     }
 }
+
+#[test]
+fn pow_coordinates() {
+    let square = Pow::try_new(0.0_f64, 10.0, 101)
+        .unwrap()
+        .exponent(2.0)
+        .unwrap();
+    assert_eq!(square.domain_to_coordinate(0.0), Some(0));
+    assert_eq!(square.domain_to_coordinate(5.0), Some(25));
+    assert_eq!(square.domain_to_coordinate(10.0), Some(100));
+    assert_eq!(square.coordinate_to_domain(25), Some(5.0));
+
+    let sqrt = Pow::try_sqrt(0_u32, 100, 11).unwrap();
+    assert_eq!(sqrt.domain_to_coordinate(25), Some(5));
+    assert_eq!(sqrt.domain_to_coordinate(101), None);
+    assert_eq!(sqrt.coordinate_to_domain(3), Some(9));
+
+    let sqrt = Sqrt::try_new(0.0_f64, 100.0, 101).unwrap();
+    assert_eq!(sqrt.domain_to_coordinate(25.0), Some(50));
+
+    assert!(Pow::try_new(0.0_f64, 10.0, 101)
+        .unwrap()
+        .exponent(0.0)
+        .is_err());
+    assert!(Pow::try_new(0.0_f64, 10.0, 101)
+        .unwrap()
+        .exponent(f64::INFINITY)
+        .is_err());
+}
+
+#[test]
+fn pow_negative_domain() {
+    let sqrt = Pow::try_sqrt(-100_i32, 100, 21).unwrap();
+    assert_eq!(sqrt.domain_to_coordinate(-100), Some(0));
+    assert_eq!(sqrt.domain_to_coordinate(-25), Some(5));
+    assert_eq!(sqrt.domain_to_coordinate(0), Some(10));
+    assert_eq!(sqrt.domain_to_coordinate(25), Some(15));
+    assert_eq!(sqrt.coordinate_to_domain(5), Some(-25));
+
+    let ticks: Vec<(i32, usize)> = sqrt.intervals(50).collect();
+    assert_eq!(
+        ticks,
+        vec![(-100, 0), (-50, 3), (0, 10), (50, 17), (100, 20)]
+    );
+}
+
+#[test]
+fn linear_ticks() {
+    let linear = Linear::try_new(0.0_f64, 1.0, 101).unwrap();
+    let ticks: Vec<(f64, usize)> = linear.ticks(5).collect();
+    assert_eq!(
+        ticks,
+        vec![
+            (0.0, 0),
+            (0.2, 20),
+            (0.4, 40),
+            (0.6, 60),
+            (0.8, 80),
+            (1.0, 100)
+        ]
+    );
+
+    let linear = Linear::try_new(360_i16, -360, 721).unwrap();
+    let ticks: Vec<i16> = linear.ticks(4).map(|(domain, _)| domain).collect();
+    assert_eq!(ticks, vec![200, 0, -200]);
+
+    assert_eq!(tick_increment(0.0, 10.0, 10), 1.0);
+    assert_eq!(tick_increment(0.0, 1.0, 10), -10.0);
+    assert_eq!(tick_increment(0.0, 100.0, 3), 50.0);
+}
+
+#[test]
+fn linear_nice() {
+    let linear = Linear::try_new(0.201479_f64, 0.996679, 100).unwrap().nice();
+    assert_eq!(linear.min, 0.2);
+    assert_eq!(linear.max, 1.0);
+
+    let linear = Linear::try_new(1_303_i32, -12, 100).unwrap().nice();
+    assert_eq!((linear.start, linear.min, linear.max), (1_400, -200, 1_400));
+}
+
+#[test]
+fn linear_range() {
+    let y_axis = Linear::try_new(-1.0_f64, 1.0, 10)
+        .unwrap()
+        .range(620, 20)
+        .unwrap();
+
+    assert_eq!(y_axis.domain_to_coordinate(-1.0), Some(620));
+    assert_eq!(y_axis.domain_to_coordinate(0.0), Some(320));
+    assert_eq!(y_axis.domain_to_coordinate(1.0), Some(20));
+    assert_eq!(y_axis.coordinate_to_domain(470), Some(-0.5));
+    assert_eq!(y_axis.coordinate_to_domain(621), None);
+    assert_eq!(y_axis.coordinate_to_domain(19), None);
+
+    let ticks: Vec<(f64, usize)> = y_axis.intervals(0.5).collect();
+    assert_eq!(
+        ticks,
+        vec![(-1.0, 620), (-0.5, 470), (0.0, 320), (0.5, 170), (1.0, 20)]
+    );
+
+    let x_axis = Linear::try_new(0_u16, 100, 10)
+        .unwrap()
+        .range(30, 130)
+        .unwrap()
+        .nice();
+    assert_eq!(x_axis.domain_to_coordinate(50), Some(80));
+    assert_eq!(x_axis.ticks(2).last(), Some((100, 130)));
+
+    assert!(Linear::try_new(0_u16, 100, 10)
+        .unwrap()
+        .range(10, 12)
+        .is_err());
+}
+
+#[test]
+fn linear_out_of_domain() {
+    let discard = Linear::try_new(-1.0_f64, 1.0, 201).unwrap();
+    assert_eq!(discard.domain_to_coordinate(1.5), None);
+    assert_eq!(discard.coordinate_to_domain(250), None);
+
+    let clamp = Linear::try_new(-1.0_f64, 1.0, 201).unwrap().clamp();
+    assert_eq!(clamp.domain_to_coordinate(1.5), Some(200));
+    assert_eq!(clamp.domain_to_coordinate(-7.0), Some(0));
+    assert_eq!(clamp.coordinate_to_domain(250), Some(1.0));
+
+    let clamp = Linear::try_new(-1.0_f64, 1.0, 201)
+        .unwrap()
+        .range(220, 20)
+        .unwrap()
+        .clamp();
+    assert_eq!(clamp.domain_to_coordinate(1.5), Some(20));
+    assert_eq!(clamp.coordinate_to_domain(0), Some(1.0));
+    assert_eq!(clamp.coordinate_to_domain(500), Some(-1.0));
+
+    let extrapolate = Linear::try_new(-1.0_f64, 1.0, 201)
+        .unwrap()
+        .range(220, 20)
+        .unwrap()
+        .extrapolate();
+    assert_eq!(extrapolate.domain_to_float_coordinate(1.5), Some(-30.0));
+    assert_eq!(extrapolate.domain_to_coordinate(1.5), None);
+    assert_eq!(extrapolate.domain_to_coordinate(-1.5), Some(270));
+    assert_eq!(extrapolate.coordinate_to_domain(10), Some(1.1));
+
+    let unsigned = Linear::try_new(10_u16, 20, 11).unwrap().extrapolate();
+    assert_eq!(unsigned.domain_to_float_coordinate(5), Some(-5.0));
+    assert_eq!(unsigned.coordinate_to_domain(15), Some(25));
+}
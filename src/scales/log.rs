@@ -0,0 +1,163 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{AddAssign, Sub},
+};
+
+use super::{
+    Continuous, ContinuousIter, ConvertToFloat, Linear, Result, ScaleError, Transformation,
+};
+
+/// Logarithmic scale, mapping a strictly positive domain onto a dimension
+///
+/// The base defaults to 10 and can be changed with [Log::base].
+pub type Log<DT> = Continuous<DT, Logarithm>;
+
+pub type LogIter<DT> = ContinuousIter<DT>;
+
+/// The transformation of a [Log] scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Logarithm {
+    base: f64,
+}
+
+impl Default for Logarithm {
+    fn default() -> Self {
+        Self { base: 10.0 }
+    }
+}
+
+impl Logarithm {
+    /// Powers of the base from `min` to `max`, together with the multiples
+    /// `1 + step`, `1 + 2 * step`, ... of each power that are less than the base
+    fn powers(&self, min: f64, max: f64, step: f64) -> Vec<f64> {
+        let step = if step > 0.0 { step } else { self.base };
+
+        let mut values = Vec::new();
+        for exponent in
+            f64::floor(self.transform(min)) as i32..=f64::ceil(self.transform(max)) as i32
+        {
+            let mut multiple = 1.0;
+            while multiple < self.base {
+                let value = if exponent < 0 {
+                    multiple / self.base.powi(-exponent)
+                } else {
+                    multiple * self.base.powi(exponent)
+                };
+                if min <= value && value <= max {
+                    values.push(value);
+                }
+                multiple += step;
+            }
+        }
+
+        values
+    }
+}
+
+impl Transformation for Logarithm {
+    const STRICTLY_POSITIVE: bool = true;
+
+    fn transform(&self, value: f64) -> f64 {
+        // the dedicated functions are exact for the powers of their base
+        if self.base == 10.0 {
+            value.log10()
+        } else if self.base == 2.0 {
+            value.log2()
+        } else {
+            value.ln() / self.base.ln()
+        }
+    }
+
+    fn untransform(&self, value: f64) -> f64 {
+        self.base.powf(value)
+    }
+
+    /// Ticks at the powers of the base, with intermediate ticks at each `step` multiple
+    fn intervals(&self, start: f64, end: f64, step: f64) -> Vec<f64> {
+        let mut values = self.powers(f64::min(start, end), f64::max(start, end), step);
+        if start > end {
+            values.reverse();
+        }
+
+        values
+    }
+
+    /// Every multiple of the powers of the base for small domains,
+    /// only the powers themselves for domains that span ten or more of them
+    fn ticks(&self, start: f64, end: f64) -> Vec<f64> {
+        let powers = f64::abs(self.transform(end) - self.transform(start));
+        let step = if powers < 10.0 { 1.0 } else { self.base };
+
+        self.intervals(start, end, step)
+    }
+}
+
+impl<DT> Log<DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+{
+    /// Use another base than 10, which must be positive, finite and not equal to 1
+    pub fn base(self, base: f64) -> Result<Self> {
+        if !(base > 0.0 && base != 1.0 && base.is_finite()) {
+            return Err(ScaleError::InvalidParameter {
+                explain: format!("base {} must be positive, finite and not 1", base),
+            });
+        }
+
+        Ok(self.with_transformation(Logarithm { base }))
+    }
+}
+
+#[test]
+fn rejects_non_positive_domain() {
+    assert!(Log::try_new(0.0_f64, 1000.0, 300).is_err());
+    assert!(Log::try_new(-10_i32, 1000, 300).is_err());
+    assert!(Log::try_new(1_u32, 1000, 3).is_err());
+}
+
+#[test]
+fn log_coordinates() {
+    use super::DomainScale;
+
+    let log = Log::try_new(1.0_f64, 1_000_000.0, 601).unwrap();
+
+    assert_eq!(log.domain_to_coordinate(1.0), Some(0));
+    assert_eq!(log.domain_to_coordinate(1_000.0), Some(300));
+    assert_eq!(log.domain_to_coordinate(1_000_000.0), Some(600));
+    assert_eq!(log.domain_to_coordinate(0.5), None);
+    assert_eq!(log.coordinate_to_domain(200), Some(100.0));
+
+    let reversed = Log::try_new(1_000_u32, 1, 301).unwrap();
+    assert_eq!(reversed.domain_to_coordinate(1_000), Some(0));
+    assert_eq!(reversed.domain_to_coordinate(10), Some(200));
+}
+
+#[test]
+fn log_ticks() {
+    use super::IterableScale;
+
+    let log = Log::try_new(1_u32, 100, 201).unwrap();
+    let ticks: Vec<u32> = log.intervals(1).map(|(domain, _)| domain).collect();
+    assert_eq!(
+        ticks,
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100]
+    );
+
+    let powers: Vec<(u32, usize)> = log.intervals(0).collect();
+    assert_eq!(powers, vec![(1, 0), (10, 100), (100, 200)]);
+
+    let log = Log::try_new(64.0_f64, 1.0, 7).unwrap().base(2.0).unwrap();
+    let powers: Vec<f64> = log.intervals(1.0).map(|(domain, _)| domain).collect();
+    assert_eq!(powers, vec![64.0, 32.0, 16.0, 8.0, 4.0, 2.0, 1.0]);
+}
+
+#[test]
+fn rejects_invalid_base() {
+    for base in [0.0, -2.0, 1.0, f64::NAN, f64::INFINITY] {
+        assert!(Log::try_new(1.0_f64, 100.0, 101)
+            .unwrap()
+            .base(base)
+            .is_err());
+    }
+}
@@ -4,5 +4,44 @@ pub use api::*;
 mod linear;
 pub use linear::*;
 
+mod continuous;
+pub use continuous::*;
+
 mod band;
 pub use band::*;
+
+mod point;
+pub use point::*;
+
+mod log;
+pub use log::*;
+
+mod symlog;
+pub use symlog::*;
+
+mod time;
+pub use time::*;
+
+mod ordinal;
+pub use ordinal::*;
+
+mod quantize;
+pub use quantize::*;
+
+mod quantile;
+pub use quantile::*;
+
+mod threshold;
+pub use threshold::*;
+
+mod chromatic;
+pub use chromatic::*;
+
+mod palette;
+pub use palette::*;
+
+mod sequential;
+pub use sequential::*;
+
+mod diverging;
+pub use diverging::*;
@@ -0,0 +1,188 @@
+use std::hash::Hash;
+
+use indexmap::{set::Iter as IndexSetIter, IndexSet};
+
+use super::Scale;
+
+/// What an [Ordinal] scale returns for values that are not in its domain
+#[derive(Debug)]
+pub enum Unknown<R> {
+    /// The value is added to the domain, and mapped accordingly
+    Implicit,
+    /// The value is mapped onto this fallback
+    Value(R),
+    /// The value is not mapped at all
+    Discard,
+}
+
+/// Ordinal scale, mapping a list of categories onto a list of arbitrary outputs,
+/// like colours, symbols or CSS classes
+///
+/// The n-th value of the domain maps to the n-th value of the range; when the range is
+/// shorter than the domain, the range is cycled.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::Ordinal;
+///
+/// let mut classes = Ordinal::new(vec!["Apples", "Pears"], vec!["odd", "even"]);
+///
+/// assert_eq!(classes.map("Pears"), Some(&"even"));
+/// // unknown values grow the domain...
+/// assert_eq!(classes.map("Bananas"), Some(&"odd"));
+/// // ...unless a fallback is given
+/// let mut classes = classes.unknown("other");
+/// assert_eq!(classes.map("Cherries"), Some(&"other"));
+/// ```
+#[derive(Debug)]
+pub struct Ordinal<D, R>
+where
+    D: Hash + Eq,
+{
+    domain: IndexSet<D>,
+    range: Vec<R>,
+    unknown: Unknown<R>,
+}
+
+impl<D, R> Ordinal<D, R>
+where
+    D: Hash + Eq,
+{
+    pub fn new<I, J>(domain: I, range: J) -> Self
+    where
+        I: IntoIterator<Item = D>,
+        J: IntoIterator<Item = R>,
+    {
+        Self {
+            domain: domain.into_iter().collect(),
+            range: range.into_iter().collect(),
+            unknown: Unknown::Implicit,
+        }
+    }
+
+    /// Map values outside of the domain onto this fallback, instead of growing the domain
+    pub fn unknown(mut self, fallback: R) -> Self {
+        self.unknown = Unknown::Value(fallback);
+
+        self
+    }
+
+    /// Do not map values outside of the domain, instead of growing the domain
+    pub fn discard_unknown(mut self) -> Self {
+        self.unknown = Unknown::Discard;
+
+        self
+    }
+
+    /// Map a value onto the range, adding it to the domain when it is unknown and
+    /// the domain may grow implicitly
+    pub fn map(&mut self, value: D) -> Option<&R> {
+        let index = match self.domain.get_index_of(&value) {
+            Some(index) => Some(index),
+            None if matches!(self.unknown, Unknown::Implicit) => {
+                Some(self.domain.insert_full(value).0)
+            }
+            None => None,
+        };
+
+        self.range_value(index)
+    }
+
+    /// Map a value onto the range, without growing the domain
+    pub fn get(&self, value: &D) -> Option<&R> {
+        self.range_value(self.domain.get_index_of(value))
+    }
+
+    pub fn domain(&self) -> IndexSetIter<'_, D> {
+        self.domain.iter()
+    }
+
+    pub fn range(&self) -> &[R] {
+        &self.range
+    }
+
+    fn range_value(&self, index: Option<usize>) -> Option<&R> {
+        match index {
+            Some(index) if !self.range.is_empty() => self.range.get(index % self.range.len()),
+            Some(_) => None,
+            None => match &self.unknown {
+                Unknown::Value(fallback) => Some(fallback),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Read-only view of the scale, which maps through [Ordinal::get] and so never grows the domain
+impl<D, R> Scale for Ordinal<D, R>
+where
+    D: Hash + Eq + Clone,
+    R: Clone,
+{
+    type Domain = D;
+    type Range = R;
+
+    fn domain_values(&self) -> Vec<D> {
+        self.domain.iter().cloned().collect()
+    }
+
+    fn range_values(&self) -> Vec<R> {
+        self.range.clone()
+    }
+
+    fn map_value(&self, value: &D) -> Option<R> {
+        self.get(value).cloned()
+    }
+
+    /// Every value of the domain together with its range value
+    fn tick_values(&self) -> Vec<(D, R)> {
+        self.domain
+            .iter()
+            .filter_map(|value| {
+                self.get(value)
+                    .map(|mapped| (value.clone(), mapped.clone()))
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn ordinal_cycles_range() {
+    let mut symbols = Ordinal::new(1..=5, vec!['○', '△', '□']);
+
+    let mapped: Vec<char> = (1..=5).filter_map(|n| symbols.map(n).copied()).collect();
+    assert_eq!(mapped, vec!['○', '△', '□', '○', '△']);
+}
+
+#[test]
+fn ordinal_unknown_values() {
+    let mut implicit = Ordinal::new(vec!["A", "B"], vec![10, 20, 30]);
+    assert_eq!(implicit.get(&"C"), None);
+    assert_eq!(implicit.map("C"), Some(&30));
+    assert_eq!(implicit.get(&"C"), Some(&30));
+    assert_eq!(implicit.map("D"), Some(&10));
+    assert_eq!(implicit.domain().count(), 4);
+
+    let mut fallback = Ordinal::new(vec!["A", "B"], vec![10, 20]).unknown(0);
+    assert_eq!(fallback.map("C"), Some(&0));
+    assert_eq!(fallback.domain().count(), 2);
+
+    let mut discard = Ordinal::new(vec!["A", "B"], vec![10, 20]).discard_unknown();
+    assert_eq!(discard.map("C"), None);
+    assert_eq!(discard.map("B"), Some(&20));
+}
+
+#[test]
+fn ordinal_over_secondary_categories() {
+    use crate::data_collections::CategorisedValues;
+
+    let categorised = CategorisedValues::new().add_data(vec![
+        (1977, "Cassette", 36_900_000),
+        (1977, "8 - Track", 127_300_000),
+        (1978, "LP/EP", 2_000_000),
+    ]);
+    let classes = Ordinal::new(categorised.secondary_categories(), vec!["fill-0", "fill-1"]);
+
+    assert_eq!(classes.get(&&"8 - Track"), Some(&"fill-1"));
+    assert_eq!(classes.get(&&"LP/EP"), Some(&"fill-0"));
+}
@@ -0,0 +1,136 @@
+use std::{fmt::Display, iter::Map, slice::Iter};
+
+use crate::{Color, Styles, Styling};
+
+/// Categorical colour palette, for distinguishing a limited number of categories
+///
+/// The palettes are those of d3, Tableau and ColorBrewer. They can be cycled through
+/// with [Palette::color], or combined with an [Ordinal](super::Ordinal) scale.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::Palette;
+///
+/// assert_eq!(Palette::Category10.color(0).to_string(), "#1f77b4");
+/// assert_eq!(Palette::Category10.color(10).to_string(), "#1f77b4");
+/// assert_eq!(Palette::Paired.colors().len(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    Category10,
+    Tableau10,
+    Accent,
+    Dark2,
+    Paired,
+    Pastel1,
+    Pastel2,
+    Set1,
+    Set2,
+    Set3,
+}
+
+pub type PaletteIter = Map<Iter<'static, u32>, fn(&u32) -> Color>;
+
+const CATEGORY10: [u32; 10] = [
+    0x1f77b4, 0xff7f0e, 0x2ca02c, 0xd62728, 0x9467bd, 0x8c564b, 0xe377c2, 0x7f7f7f, 0xbcbd22,
+    0x17becf,
+];
+const TABLEAU10: [u32; 10] = [
+    0x4e79a7, 0xf28e2c, 0xe15759, 0x76b7b2, 0x59a14f, 0xedc949, 0xaf7aa1, 0xff9da7, 0x9c755f,
+    0xbab0ab,
+];
+const ACCENT: [u32; 8] = [
+    0x7fc97f, 0xbeaed4, 0xfdc086, 0xffff99, 0x386cb0, 0xf0027f, 0xbf5b17, 0x666666,
+];
+const DARK2: [u32; 8] = [
+    0x1b9e77, 0xd95f02, 0x7570b3, 0xe7298a, 0x66a61e, 0xe6ab02, 0xa6761d, 0x666666,
+];
+const PAIRED: [u32; 12] = [
+    0xa6cee3, 0x1f78b4, 0xb2df8a, 0x33a02c, 0xfb9a99, 0xe31a1c, 0xfdbf6f, 0xff7f00, 0xcab2d6,
+    0x6a3d9a, 0xffff99, 0xb15928,
+];
+const PASTEL1: [u32; 9] = [
+    0xfbb4ae, 0xb3cde3, 0xccebc5, 0xdecbe4, 0xfed9a6, 0xffffcc, 0xe5d8bd, 0xfddaec, 0xf2f2f2,
+];
+const PASTEL2: [u32; 8] = [
+    0xb3e2cd, 0xfdcdac, 0xcbd5e8, 0xf4cae4, 0xe6f5c9, 0xfff2ae, 0xf1e2cc, 0xcccccc,
+];
+const SET1: [u32; 9] = [
+    0xe41a1c, 0x377eb8, 0x4daf4a, 0x984ea3, 0xff7f00, 0xffff33, 0xa65628, 0xf781bf, 0x999999,
+];
+const SET2: [u32; 8] = [
+    0x66c2a5, 0xfc8d62, 0x8da0cb, 0xe78ac3, 0xa6d854, 0xffd92f, 0xe5c494, 0xb3b3b3,
+];
+const SET3: [u32; 12] = [
+    0x8dd3c7, 0xffffb3, 0xbebada, 0xfb8072, 0x80b1d3, 0xfdb462, 0xb3de69, 0xfccde5, 0xd9d9d9,
+    0xbc80bd, 0xccebc5, 0xffed6f,
+];
+
+impl Palette {
+    /// The colours of the palette, as `0xRRGGBB`
+    pub fn hex_codes(&self) -> &'static [u32] {
+        match self {
+            Palette::Category10 => &CATEGORY10,
+            Palette::Tableau10 => &TABLEAU10,
+            Palette::Accent => &ACCENT,
+            Palette::Dark2 => &DARK2,
+            Palette::Paired => &PAIRED,
+            Palette::Pastel1 => &PASTEL1,
+            Palette::Pastel2 => &PASTEL2,
+            Palette::Set1 => &SET1,
+            Palette::Set2 => &SET2,
+            Palette::Set3 => &SET3,
+        }
+    }
+
+    pub fn colors(&self) -> PaletteIter {
+        self.hex_codes().iter().map(|hex| Color::Hex(*hex))
+    }
+
+    /// The colour at the index, starting over when the index exceeds the palette
+    pub fn color(&self, index: usize) -> Color {
+        let hex_codes = self.hex_codes();
+
+        Color::Hex(hex_codes[index % hex_codes.len()])
+    }
+
+    /// A fill rule for each category, e.g. for each of
+    /// [CategorisedValues::secondary_categories](crate::data_collections::CategorisedValues::secondary_categories);
+    /// the selector receives the index and the category
+    pub fn fill_rules<I, S, ST>(&self, categories: I, selector: S) -> Styles
+    where
+        I: IntoIterator,
+        S: Fn(usize, I::Item) -> ST,
+        ST: Display,
+    {
+        categories
+            .into_iter()
+            .enumerate()
+            .fold(Styles::new(), |styles, (index, category)| {
+                styles.add_rule(
+                    selector(index, category).to_string(),
+                    vec![Styling::Fill(self.color(index))],
+                )
+            })
+    }
+}
+
+#[test]
+fn fill_rules_for_secondary_categories() {
+    use crate::data_collections::CategorisedValues;
+    use simple_xml_serialize::XMLElement;
+
+    let categorised = CategorisedValues::new().add_data(vec![
+        (1977, "Cassette", 36_900_000),
+        (1977, "8 - Track", 127_300_000),
+        (1978, "LP/EP", 2_000_000),
+    ]);
+    let styles = Palette::Dark2.fill_rules(categorised.secondary_categories(), |index, _| {
+        format!(".segment-{}", index)
+    });
+
+    assert_eq!(
+        XMLElement::from(styles).to_string(),
+        "<style>.segment-0 {\n\tfill: #1b9e77;\n}\n.segment-1 {\n\tfill: #d95f02;\n}\n.segment-2 {\n\tfill: #7570b3;\n}\n</style>"
+    );
+}
@@ -0,0 +1,342 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use indexmap::{set::Iter as IndexSetIter, IndexSet};
+
+use super::{
+    spread_bands, too_small_range, unique_domain, CoordinateRange, Result, Scale, ScaleError,
+};
+
+/// Point scale, placing each value of a categorical domain on a single coordinate
+///
+/// This is a [Band](super::Band) scale whose bands have no width: the points are spread
+/// evenly over the dimension, with an outer padding before the first and after the
+/// last point, expressed as a fraction of the step between two points.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::Point;
+///
+/// let point = Point::try_new(vec!["Mon", "Tue", "Wed"], 101).unwrap();
+/// let coordinates: Vec<usize> = point.iter().map(|(_, coordinate)| coordinate).collect();
+///
+/// assert_eq!(coordinates, vec![0, 50, 100]);
+/// ```
+#[derive(Debug)]
+pub struct Point<DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
+{
+    dimension: usize,
+    domain: IndexSet<DT>,
+    padding: f64,
+    align: f64,
+    range: CoordinateRange,
+}
+
+pub struct PointIter<'i, DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
+{
+    step: f64,
+    current: f64,
+    range: CoordinateRange,
+    iter: IndexSetIter<'i, DT>,
+}
+
+pub struct FloatPointIter<'i, DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
+{
+    step: f64,
+    current: f64,
+    range: CoordinateRange,
+    iter: IndexSetIter<'i, DT>,
+}
+
+impl<DT> Point<DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
+{
+    /// A point for each value of the domain, which must not contain duplicates
+    ///
+    /// Dimensions with fewer coordinates than points are rejected.
+    pub fn try_new<I>(domain: I, dimension: usize) -> Result<Self>
+    where
+        I: IntoIterator<Item = DT>,
+    {
+        if dimension == 0 {
+            return Err(ScaleError::DimensionTooSmall);
+        }
+
+        Self {
+            dimension,
+            domain: unique_domain(domain)?,
+            padding: 0.0,
+            align: 0.5,
+            range: CoordinateRange::new(0, dimension - 1),
+        }
+        .range(0, dimension - 1)
+    }
+
+    /// Place the points on the coordinates from `start` to `end`, instead of from zero
+    ///
+    /// When the end is smaller than the start, the first point is placed nearest to the start.
+    /// Ranges with fewer coordinates than points are rejected.
+    pub fn range(mut self, start: usize, end: usize) -> Result<Self> {
+        let range = CoordinateRange::new(start, end);
+        if range.dimension() < self.domain.len() {
+            return Err(too_small_range(range, self.domain.len()));
+        }
+
+        self.range = range;
+        self.dimension = range.dimension();
+
+        Ok(self)
+    }
+
+    /// Outer padding, as a fraction of the step; negative paddings are ignored
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.padding = if padding >= 0.0 && padding.is_finite() {
+            padding
+        } else {
+            0.0
+        };
+
+        self
+    }
+
+    /// Distribution of the outer padding, from 0 (all after the last point)
+    /// to 1 (all before the first point); values outside that range are ignored
+    pub fn align(mut self, align: f64) -> Self {
+        self.align = if (0.0..=1.0).contains(&align) {
+            align
+        } else {
+            0.5
+        };
+
+        self
+    }
+
+    /// Distance between two consecutive points
+    pub fn step(&self) -> f64 {
+        self.spread().0
+    }
+
+    /// Coordinate of the point of a domain value
+    pub fn point_of(&self, value: &DT) -> Option<usize> {
+        self.domain.get_index_of(value).map(|index| {
+            self.range
+                .whole_coordinate(f64::round(self.point_offset(index)) as usize)
+        })
+    }
+
+    /// Exact, sub-pixel, coordinate of the point of a domain value
+    pub fn float_point_of(&self, value: &DT) -> Option<f64> {
+        self.domain
+            .get_index_of(value)
+            .map(|index| self.range.coordinate(self.point_offset(index)))
+    }
+
+    /// Offset of the point with the given index from the start of the range
+    fn point_offset(&self, index: usize) -> f64 {
+        let (step, first) = self.spread();
+
+        first + step * index as f64
+    }
+
+    /// The domain value whose point is nearest to the coordinate,
+    /// or [None] when the coordinate lies outside of the range
+    pub fn coordinate_to_domain(&self, coordinate: usize) -> Option<&DT> {
+        self.nearest_point(coordinate as f64)
+    }
+
+    fn nearest_point(&self, coordinate: f64) -> Option<&DT> {
+        let offset = self.range.float_offset(coordinate);
+        if self.domain.is_empty() || offset < 0.0 || offset > (self.dimension - 1) as f64 {
+            return None;
+        }
+
+        let (step, first) = self.spread();
+        let index = if step > 0.0 {
+            f64::round((offset - first) / step).clamp(0.0, (self.domain.len() - 1) as f64)
+        } else {
+            0.0
+        };
+
+        self.domain.get_index(index as usize)
+    }
+
+    pub fn iter<'i>(&'i self) -> PointIter<'i, DT> {
+        let (step, current) = self.spread();
+
+        PointIter {
+            step,
+            current,
+            range: self.range,
+            iter: self.domain.iter(),
+        }
+    }
+
+    /// Iterate over the exact, sub-pixel, coordinate of each point
+    pub fn float_iter<'i>(&'i self) -> FloatPointIter<'i, DT> {
+        let (step, current) = self.spread();
+
+        FloatPointIter {
+            step,
+            current,
+            range: self.range,
+            iter: self.domain.iter(),
+        }
+    }
+
+    /// Points are bands without inner space, spread from the first to the last coordinate
+    fn spread(&self) -> (f64, f64) {
+        spread_bands(
+            self.domain.len(),
+            (self.dimension - 1) as f64,
+            1.0,
+            self.padding,
+            self.align,
+        )
+    }
+}
+
+impl<'i, DT> Iterator for PointIter<'i, DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
+{
+    type Item = (&'i DT, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|domain| {
+            let result = (
+                domain,
+                self.range
+                    .whole_coordinate(f64::round(self.current) as usize),
+            );
+
+            self.current += self.step;
+
+            result
+        })
+    }
+}
+
+impl<'i, DT> Iterator for FloatPointIter<'i, DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq,
+{
+    type Item = (&'i DT, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|domain| {
+            let result = (domain, self.range.coordinate(self.current));
+
+            self.current += self.step;
+
+            result
+        })
+    }
+}
+
+impl<DT> Scale for Point<DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Hash + Eq + Clone,
+{
+    type Domain = DT;
+    type Range = f64;
+
+    fn domain_values(&self) -> Vec<DT> {
+        self.domain.iter().cloned().collect()
+    }
+
+    fn range_values(&self) -> Vec<f64> {
+        vec![self.range.start() as f64, self.range.end() as f64]
+    }
+
+    fn map_value(&self, value: &DT) -> Option<f64> {
+        self.float_point_of(value)
+    }
+
+    /// The domain value whose point is nearest to the coordinate
+    fn invert_value(&self, coordinate: &f64) -> Option<DT> {
+        self.nearest_point(*coordinate).cloned()
+    }
+
+    fn tick_values(&self) -> Vec<(DT, f64)> {
+        self.float_iter()
+            .map(|(value, coordinate)| (value.clone(), coordinate))
+            .collect()
+    }
+}
+
+#[test]
+fn point_padding() {
+    let point = Point::try_new(vec!["Apples", "Pears", "Bananas"], 401)
+        .unwrap()
+        .padding(1.0);
+    assert_eq!(point.step(), 100.0);
+
+    let coordinates: Vec<usize> = point.iter().map(|(_, coordinate)| coordinate).collect();
+    assert_eq!(coordinates, vec![100, 200, 300]);
+
+    let single = Point::try_new(vec!["Apples"], 401).unwrap();
+    assert_eq!(single.iter().next(), Some((&"Apples", 200)));
+
+    let left = Point::try_new(vec!["Apples", "Pears", "Bananas"], 401)
+        .unwrap()
+        .padding(1.0)
+        .align(0.0);
+    let coordinates: Vec<f64> = left
+        .float_iter()
+        .map(|(_, coordinate)| coordinate)
+        .collect();
+    assert_eq!(coordinates, vec![0.0, 100.0, 200.0]);
+}
+
+#[test]
+fn point_range() {
+    let upwards = Point::try_new(1..=5, 5).unwrap().range(450, 50).unwrap();
+    let points: Vec<(&i32, usize)> = upwards.iter().collect();
+
+    assert_eq!(
+        points,
+        vec![(&1, 450), (&2, 350), (&3, 250), (&4, 150), (&5, 50)]
+    );
+
+    assert!(Point::try_new(1..=5, 5).unwrap().range(10, 12).is_err());
+    assert!(Point::try_new(1..=5, 2).is_err());
+    assert!(Point::try_new(1..=5, 0).is_err());
+
+    for (domain, coordinate) in upwards.iter() {
+        assert_eq!(upwards.point_of(domain), Some(coordinate));
+        assert_eq!(upwards.coordinate_to_domain(coordinate), Some(domain));
+    }
+}
+
+#[test]
+fn point_lookup() {
+    let point = Point::try_new(vec!["Apples", "Pears", "Bananas"], 401)
+        .unwrap()
+        .padding(1.0);
+
+    for (domain, coordinate) in point.iter() {
+        assert_eq!(point.point_of(domain), Some(coordinate));
+        assert_eq!(point.coordinate_to_domain(coordinate), Some(domain));
+    }
+    assert_eq!(point.float_point_of(&"Pears"), Some(200.0));
+    assert_eq!(point.point_of(&"Cherries"), None);
+
+    // the nearest point, also within the outer padding
+    assert_eq!(point.coordinate_to_domain(0), Some(&"Apples"));
+    assert_eq!(point.coordinate_to_domain(249), Some(&"Pears"));
+    assert_eq!(point.coordinate_to_domain(251), Some(&"Bananas"));
+    assert_eq!(point.coordinate_to_domain(401), None);
+    assert_eq!(point.invert_value(&310.5), Some("Bananas"));
+    assert_eq!(point.invert_value(&-1.0), None);
+
+    assert!(Point::try_new(vec!["Apples", "Apples"], 401).is_err());
+}
@@ -0,0 +1,236 @@
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    ops::{AddAssign, Sub},
+};
+
+use super::{ConvertToFloat, Linear, Result, Scale, ScaleError};
+
+/// Quantile scale, dividing a sample of the domain into groups of equal size,
+/// one for each value of a discrete range
+///
+/// The boundaries between the groups are the quantiles of the sample,
+/// interpolated like the R-7 method of R and d3.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::Quantile;
+///
+/// let halves = Quantile::try_new(vec![1, 3, 4, 10, 20], vec!["low", "high"]).unwrap();
+///
+/// assert_eq!(halves.quantiles(), vec![4]);
+/// assert_eq!(halves.map(5), Some(&"high"));
+/// assert_eq!(halves.invert_extent(&"low"), Some((1, 4)));
+/// ```
+#[derive(Debug)]
+pub struct Quantile<DT, R>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT>,
+{
+    sample: Vec<f64>,
+    thresholds: Vec<f64>,
+    range: Vec<R>,
+    domain: PhantomData<DT>,
+}
+
+impl<DT, R> Quantile<DT, R>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+{
+    /// Compute the quantiles of the sample; values that are not a number are left out
+    pub fn try_new<I, J>(sample: I, range: J) -> Result<Self>
+    where
+        I: IntoIterator<Item = DT>,
+        J: IntoIterator<Item = R>,
+    {
+        let mut sample: Vec<f64> = sample
+            .into_iter()
+            .map(Self::to_float)
+            .filter(|value| !value.is_nan())
+            .collect();
+        if sample.is_empty() {
+            return Err(ScaleError::InvalidDomain {
+                explain: "a quantile scale needs a sample with at least one number".to_string(),
+            });
+        }
+        sample.sort_by(f64::total_cmp);
+
+        let range: Vec<R> = range.into_iter().collect();
+        if range.is_empty() {
+            return Err(ScaleError::InvalidRange {
+                explain: "a quantile scale needs at least one range value".to_string(),
+            });
+        }
+
+        let n = range.len();
+        let thresholds = (1..n)
+            .map(|i| quantile(&sample, i as f64 / n as f64))
+            .collect();
+
+        Ok(Self {
+            sample,
+            thresholds,
+            range,
+            domain: PhantomData,
+        })
+    }
+
+    fn to_float(value: DT) -> f64 {
+        <Linear<DT> as ConvertToFloat<DT>>::to_float(value)
+    }
+
+    fn from_float(value: f64) -> DT {
+        <Linear<DT> as ConvertToFloat<DT>>::from_float(value)
+    }
+
+    /// The smallest domain value that [Quantile::map] places at or above the threshold,
+    /// i.e. thresholds between two integers are rounded up
+    fn lower_bound(threshold: f64) -> DT {
+        let mut value = Self::from_float(threshold);
+        if Self::to_float(value) < threshold {
+            value += <Linear<DT> as ConvertToFloat<DT>>::ADJUST;
+        }
+
+        value
+    }
+
+    /// The range value of the group that contains the value,
+    /// or [None] when the value is not a number
+    pub fn map(&self, value: DT) -> Option<&R> {
+        let value = Self::to_float(value);
+        if value.is_nan() {
+            None
+        } else {
+            self.range.get(
+                self.thresholds
+                    .partition_point(|threshold| *threshold <= value),
+            )
+        }
+    }
+
+    /// The boundaries between the groups
+    pub fn quantiles(&self) -> Vec<DT> {
+        self.thresholds
+            .iter()
+            .map(|threshold| Self::lower_bound(*threshold))
+            .collect()
+    }
+
+    pub fn range(&self) -> &[R] {
+        &self.range
+    }
+
+    /// The interval of the domain that maps onto the first occurrence of a range value,
+    /// bounded by the smallest and largest value of the sample
+    pub fn invert_extent(&self, value: &R) -> Option<(DT, DT)>
+    where
+        R: PartialEq,
+    {
+        self.range
+            .iter()
+            .position(|candidate| candidate == value)
+            .map(|index| {
+                let before = index
+                    .checked_sub(1)
+                    .map_or(Self::from_float(self.sample[0]), |before| {
+                        Self::lower_bound(self.thresholds[before])
+                    });
+                let after = self.thresholds.get(index).map_or(
+                    Self::from_float(self.sample[self.sample.len() - 1]),
+                    |after| Self::lower_bound(*after),
+                );
+
+                (before, after)
+            })
+    }
+}
+
+impl<DT, R> Scale for Quantile<DT, R>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    R: Clone,
+{
+    type Domain = DT;
+    type Range = R;
+
+    /// The sorted sample
+    fn domain_values(&self) -> Vec<DT> {
+        self.sample
+            .iter()
+            .map(|value| Self::from_float(*value))
+            .collect()
+    }
+
+    fn range_values(&self) -> Vec<R> {
+        self.range.clone()
+    }
+
+    fn map_value(&self, value: &DT) -> Option<R> {
+        self.map(*value).cloned()
+    }
+
+    /// The start of each group, together with its range value
+    fn tick_values(&self) -> Vec<(DT, R)> {
+        std::iter::once(Self::from_float(self.sample[0]))
+            .chain(self.quantiles())
+            .zip(self.range.iter().cloned())
+            .collect()
+    }
+}
+
+/// The p-quantile of a sorted, non-empty, sample
+fn quantile(sorted: &[f64], p: f64) -> f64 {
+    let position = (sorted.len() - 1) as f64 * p;
+    let below = f64::floor(position) as usize;
+
+    match sorted.get(below + 1) {
+        Some(above) => sorted[below] + (above - sorted[below]) * (position - below as f64),
+        None => sorted[below],
+    }
+}
+
+#[test]
+fn quantile_groups() {
+    let sample = vec![
+        3.0,
+        6.0,
+        7.0,
+        8.0,
+        8.0,
+        10.0,
+        13.0,
+        15.0,
+        16.0,
+        20.0,
+        f64::NAN,
+    ];
+    let quartiles = Quantile::try_new(sample, 1..=4).unwrap();
+
+    assert_eq!(quartiles.quantiles(), vec![7.25, 9.0, 14.5]);
+    assert_eq!(quartiles.map(7.0), Some(&1));
+    assert_eq!(quartiles.map(7.25), Some(&2));
+    assert_eq!(quartiles.map(100.0), Some(&4));
+    assert_eq!(quartiles.map(f64::NAN), None);
+
+    assert_eq!(quartiles.invert_extent(&1), Some((3.0, 7.25)));
+    assert_eq!(quartiles.invert_extent(&3), Some((9.0, 14.5)));
+    assert_eq!(quartiles.invert_extent(&4), Some((14.5, 20.0)));
+    assert_eq!(quartiles.invert_extent(&5), None);
+
+    assert!(Quantile::try_new(Vec::<f64>::new(), 1..=4).is_err());
+    assert!(Quantile::<f64, u8>::try_new(vec![1.0], []).is_err());
+}
+
+#[test]
+fn integer_quantiles() {
+    let halves = Quantile::try_new(vec![1, 2], vec!["a", "b"]).unwrap();
+
+    // the threshold 1.5 is rounded up to the first integer that maps onto "b"
+    assert_eq!(halves.quantiles(), vec![2]);
+    assert_eq!(halves.map(1), Some(&"a"));
+    assert_eq!(halves.map(2), Some(&"b"));
+    assert_eq!(halves.invert_extent(&"a"), Some((1, 2)));
+    assert_eq!(halves.invert_extent(&"b"), Some((2, 2)));
+}
@@ -0,0 +1,205 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{AddAssign, Sub},
+};
+
+use super::{ConvertToFloat, Linear, Result, Scale, ScaleError};
+
+/// Quantize scale, dividing a continuous domain into equal intervals,
+/// one for each value of a discrete range
+///
+/// Values outside of the domain map onto the first or last value of the range.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::Quantize;
+///
+/// let heat = Quantize::try_new(0.0, 30.0, vec!["cold", "mild", "hot"]).unwrap();
+///
+/// assert_eq!(heat.map(12.5), Some(&"mild"));
+/// assert_eq!(heat.invert_extent(&"hot"), Some((20.0, 30.0)));
+/// ```
+#[derive(Debug)]
+pub struct Quantize<DT, R>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT>,
+{
+    min: DT,
+    max: DT,
+    thresholds: Vec<f64>,
+    range: Vec<R>,
+}
+
+impl<DT, R> Quantize<DT, R>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+{
+    pub fn try_new<I>(start: DT, end: DT, range: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = R>,
+    {
+        let range: Vec<R> = range.into_iter().collect();
+        if range.is_empty() {
+            return Err(ScaleError::InvalidRange {
+                explain: "a quantize scale needs at least one range value".to_string(),
+            });
+        }
+
+        let (min, max) = if start < end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let (float_min, float_max) = (Self::to_float(min), Self::to_float(max));
+        let n = range.len();
+        let thresholds = (1..n)
+            .map(|i| float_min + (float_max - float_min) * i as f64 / n as f64)
+            .collect();
+
+        Ok(Self {
+            min,
+            max,
+            thresholds,
+            range,
+        })
+    }
+
+    fn to_float(value: DT) -> f64 {
+        <Linear<DT> as ConvertToFloat<DT>>::to_float(value)
+    }
+
+    /// The smallest domain value that [Quantize::map] places at or above the threshold,
+    /// i.e. thresholds between two integers are rounded up
+    fn lower_bound(threshold: f64) -> DT {
+        let mut value = <Linear<DT> as ConvertToFloat<DT>>::from_float(threshold);
+        if Self::to_float(value) < threshold {
+            value += <Linear<DT> as ConvertToFloat<DT>>::ADJUST;
+        }
+
+        value
+    }
+
+    /// The range value of the interval that contains the value,
+    /// or [None] when the value is not a number
+    pub fn map(&self, value: DT) -> Option<&R> {
+        let value = Self::to_float(value);
+        if value.is_nan() {
+            None
+        } else {
+            self.range.get(
+                self.thresholds
+                    .partition_point(|threshold| *threshold <= value),
+            )
+        }
+    }
+
+    /// The boundaries between the intervals, each the first value of the next interval
+    pub fn thresholds(&self) -> Vec<DT> {
+        self.thresholds
+            .iter()
+            .map(|threshold| Self::lower_bound(*threshold))
+            .collect()
+    }
+
+    pub fn range(&self) -> &[R] {
+        &self.range
+    }
+
+    /// The interval of the domain that maps onto the first occurrence of a range value
+    ///
+    /// The interval includes its start, but not its end unless it is the end of the domain.
+    pub fn invert_extent(&self, value: &R) -> Option<(DT, DT)>
+    where
+        R: PartialEq,
+    {
+        self.range
+            .iter()
+            .position(|candidate| candidate == value)
+            .map(|index| {
+                (
+                    index.checked_sub(1).map_or(self.min, |before| {
+                        Self::lower_bound(self.thresholds[before])
+                    }),
+                    self.thresholds
+                        .get(index)
+                        .map_or(self.max, |after| Self::lower_bound(*after)),
+                )
+            })
+    }
+}
+
+impl<DT, R> Scale for Quantize<DT, R>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    R: Clone,
+{
+    type Domain = DT;
+    type Range = R;
+
+    fn domain_values(&self) -> Vec<DT> {
+        vec![self.min, self.max]
+    }
+
+    fn range_values(&self) -> Vec<R> {
+        self.range.clone()
+    }
+
+    fn map_value(&self, value: &DT) -> Option<R> {
+        self.map(*value).cloned()
+    }
+
+    /// The start of each interval, together with its range value
+    fn tick_values(&self) -> Vec<(DT, R)> {
+        std::iter::once(self.min)
+            .chain(self.thresholds())
+            .zip(self.range.iter().cloned())
+            .collect()
+    }
+}
+
+#[test]
+fn quantize_intervals() {
+    let quantize = Quantize::try_new(100_u32, 0, ["low", "medium", "high", "extreme"]).unwrap();
+
+    assert_eq!(quantize.thresholds(), vec![25, 50, 75]);
+    assert_eq!(quantize.map(0), Some(&"low"));
+    assert_eq!(quantize.map(25), Some(&"medium"));
+    assert_eq!(quantize.map(74), Some(&"high"));
+    assert_eq!(quantize.map(1_000), Some(&"extreme"));
+
+    assert_eq!(quantize.invert_extent(&"low"), Some((0, 25)));
+    assert_eq!(quantize.invert_extent(&"extreme"), Some((75, 100)));
+    assert_eq!(quantize.invert_extent(&"none"), None);
+
+    assert_eq!(
+        Quantize::try_new(0.0, 1.0, ["only"]).unwrap().map(f64::NAN),
+        None
+    );
+    assert!(Quantize::<f64, &str>::try_new(0.0, 1.0, []).is_err());
+}
+
+#[test]
+fn quantize_integer_boundaries() {
+    let quantize = Quantize::try_new(0_i32, 10, ["low", "medium", "high"]).unwrap();
+
+    // the thresholds 3.33 and 6.67 fall between integers
+    assert_eq!(quantize.thresholds(), vec![4, 7]);
+    assert_eq!(quantize.map(3), Some(&"low"));
+    assert_eq!(quantize.map(4), Some(&"medium"));
+    assert_eq!(quantize.map(6), Some(&"medium"));
+    assert_eq!(quantize.map(7), Some(&"high"));
+    assert_eq!(quantize.invert_extent(&"medium"), Some((4, 7)));
+
+    let negative = Quantize::try_new(-10_i32, 0, ["low", "medium", "high"]).unwrap();
+    assert_eq!(negative.thresholds(), vec![-6, -3]);
+    assert_eq!(negative.map(-7), Some(&"low"));
+    assert_eq!(negative.map(-6), Some(&"medium"));
+    assert_eq!(negative.map(-4), Some(&"medium"));
+    assert_eq!(negative.map(-3), Some(&"high"));
+
+    for (start, value) in quantize.tick_values() {
+        assert_eq!(quantize.map(start), Some(&value));
+    }
+}
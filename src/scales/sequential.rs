@@ -0,0 +1,166 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{AddAssign, Sub},
+};
+
+use crate::Color;
+
+use super::{tick_values, ConvertToFloat, Linear, Result, Scale, ScaleError, DEFAULT_TICK_COUNT};
+
+/// Sequential colour scale, mapping a continuous domain through an interpolator onto colours
+///
+/// The domain is mapped linearly onto the unit interval, which is passed to the interpolator,
+/// e.g. one of the built-in schemes like [interpolate_viridis](super::interpolate_viridis).
+/// Values outside of the domain are clamped.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::{interpolate_greys, Sequential};
+///
+/// let shade = Sequential::try_new(0_u32, 100, interpolate_greys).unwrap();
+///
+/// assert_eq!(shade.map(0).unwrap().to_string(), "#ffffff");
+/// assert_eq!(shade.map(150).unwrap().to_string(), "#000000");
+/// ```
+pub struct Sequential<DT, I>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT>,
+    I: Fn(f64) -> Color,
+{
+    start: DT,
+    end: DT,
+    interpolator: I,
+}
+
+impl<DT, I> Sequential<DT, I>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    I: Fn(f64) -> Color,
+{
+    pub fn try_new(start: DT, end: DT, interpolator: I) -> Result<Self> {
+        for value in [start, end] {
+            if value < <Linear<DT> as ConvertToFloat<DT>>::MIN
+                || value > <Linear<DT> as ConvertToFloat<DT>>::MAX
+            {
+                return Err(ScaleError::OutOfRange {
+                    explain: format!(
+                        "domain value {} is out of range; must be between {} and {}",
+                        value,
+                        <Linear<DT> as ConvertToFloat<DT>>::MIN,
+                        <Linear<DT> as ConvertToFloat<DT>>::MAX
+                    ),
+                });
+            }
+        }
+
+        Ok(Self {
+            start,
+            end,
+            interpolator,
+        })
+    }
+
+    fn to_float(value: DT) -> f64 {
+        <Linear<DT> as ConvertToFloat<DT>>::to_float(value)
+    }
+
+    /// The colour of a value, or [None] when the value is not a number
+    pub fn map(&self, value: DT) -> Option<Color> {
+        let (start, end) = (Self::to_float(self.start), Self::to_float(self.end));
+        let value = Self::to_float(value);
+
+        if value.is_nan() {
+            None
+        } else {
+            let t = if start == end {
+                0.0
+            } else {
+                (value - start) / (end - start)
+            };
+
+            Some((self.interpolator)(t.clamp(0.0, 1.0)))
+        }
+    }
+}
+
+impl<DT, I> Scale for Sequential<DT, I>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+    I: Fn(f64) -> Color,
+{
+    type Domain = DT;
+    type Range = Color;
+
+    fn domain_values(&self) -> Vec<DT> {
+        vec![self.start, self.end]
+    }
+
+    fn range_values(&self) -> Vec<Color> {
+        vec![(self.interpolator)(0.0), (self.interpolator)(1.0)]
+    }
+
+    fn map_value(&self, value: &DT) -> Option<Color> {
+        self.map(*value)
+    }
+
+    /// Round values, spaced like the ticks of a [Linear] scale, together with their colours
+    fn tick_values(&self) -> Vec<(DT, Color)> {
+        color_ticks(self.start, self.end, |value| self.map(value))
+    }
+}
+
+/// Round values from `start` to `end`, spaced like the ticks of a [Linear] scale,
+/// together with the colours `map` assigns them
+pub(crate) fn color_ticks<DT>(
+    start: DT,
+    end: DT,
+    map: impl Fn(DT) -> Option<Color>,
+) -> Vec<(DT, Color)>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+{
+    let (start, end) = (
+        <Linear<DT> as ConvertToFloat<DT>>::to_float(start),
+        <Linear<DT> as ConvertToFloat<DT>>::to_float(end),
+    );
+    let mut values = tick_values(
+        f64::min(start, end),
+        f64::max(start, end),
+        DEFAULT_TICK_COUNT,
+    );
+    if start > end {
+        values.reverse();
+    }
+
+    values
+        .into_iter()
+        .map(<Linear<DT> as ConvertToFloat<DT>>::from_float)
+        .filter_map(|value| map(value).map(|color| (value, color)))
+        .collect()
+}
+
+#[test]
+fn sequential_colors() {
+    use super::{interpolate_blues, interpolate_viridis};
+
+    let viridis = Sequential::try_new(-1.0, 1.0, interpolate_viridis).unwrap();
+    assert_eq!(
+        viridis.map(0.0).unwrap().to_string(),
+        interpolate_viridis(0.5).to_string()
+    );
+    assert_eq!(
+        viridis.map(-5.0).unwrap().to_string(),
+        interpolate_viridis(0.0).to_string()
+    );
+    assert!(viridis.map(f64::NAN).is_none());
+
+    let reversed = Sequential::try_new(10_i32, 0, interpolate_blues).unwrap();
+    assert_eq!(reversed.map(10).unwrap().to_string(), "#f7fbff");
+    assert_eq!(reversed.map(0).unwrap().to_string(), "#08306b");
+
+    let custom = Sequential::try_new(0_u32, 255, |t| Color::Hex((t * 255.0) as u32)).unwrap();
+    assert_eq!(custom.map(17).unwrap().to_string(), "#000011");
+}
@@ -0,0 +1,165 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{AddAssign, Sub},
+};
+
+use super::{
+    tick_values, Continuous, ContinuousIter, ConvertToFloat, Linear, Transformation,
+    DEFAULT_TICK_COUNT,
+};
+
+/// Symmetric logarithmic scale, for domains that contain zero or cross it
+///
+/// Values are transformed by `sign(x) * ln(1 + |x / c|)`, where the constant `c`
+/// sets the size of the region around zero that behaves nearly linearly.
+/// The constant defaults to 1 and can be changed with [SymLog::constant].
+pub type SymLog<DT> = Continuous<DT, SymmetricLogarithm>;
+
+pub type SymLogIter<DT> = ContinuousIter<DT>;
+
+/// The transformation of a [SymLog] scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymmetricLogarithm {
+    constant: f64,
+}
+
+impl Default for SymmetricLogarithm {
+    fn default() -> Self {
+        Self { constant: 1.0 }
+    }
+}
+
+impl Transformation for SymmetricLogarithm {
+    fn transform(&self, value: f64) -> f64 {
+        value.signum() * f64::ln_1p(value.abs() / self.constant)
+    }
+
+    fn untransform(&self, value: f64) -> f64 {
+        value.signum() * f64::exp_m1(value.abs()) * self.constant
+    }
+
+    /// Zero, and the powers of ten from the constant onwards on both sides of zero,
+    /// together with the multiples `1 + step`, `1 + 2 * step`, ... of each power below ten
+    fn intervals(&self, start: f64, end: f64, step: f64) -> Vec<f64> {
+        let step = if step > 0.0 { step } else { 10.0 };
+        let (min, max) = (f64::min(start, end), f64::max(start, end));
+        let largest = f64::max(min.abs(), max.abs());
+
+        let mut values = Vec::new();
+        if min <= 0.0 && 0.0 <= max {
+            values.push(0.0);
+        }
+        if largest >= self.constant {
+            let first_exponent = f64::floor(self.constant.log10()) as i32;
+            let last_exponent = f64::ceil(largest.log10()) as i32;
+            for exponent in first_exponent..=last_exponent {
+                let mut multiple = 1.0;
+                while multiple < 10.0 {
+                    let value = if exponent < 0 {
+                        multiple / 10_f64.powi(-exponent)
+                    } else {
+                        multiple * 10_f64.powi(exponent)
+                    };
+                    for signed in [-value, value] {
+                        if min <= signed && signed <= max {
+                            values.push(signed);
+                        }
+                    }
+                    multiple += step;
+                }
+            }
+        }
+
+        values.sort_by(f64::total_cmp);
+        if start > end {
+            values.reverse();
+        }
+
+        values
+    }
+
+    /// Round values, spaced like the ticks of a [Linear] scale, as d3 does
+    fn ticks(&self, start: f64, end: f64) -> Vec<f64> {
+        let mut values = tick_values(
+            f64::min(start, end),
+            f64::max(start, end),
+            DEFAULT_TICK_COUNT,
+        );
+        if start > end {
+            values.reverse();
+        }
+
+        values
+    }
+}
+
+impl<DT> SymLog<DT>
+where
+    DT: PartialEq + PartialOrd + Debug + Display + Copy + Sub<DT, Output = DT> + AddAssign<DT>,
+    Linear<DT>: ConvertToFloat<DT>,
+{
+    /// Set the constant of the linear region; constants that are not positive are ignored
+    pub fn constant(self, constant: f64) -> Self {
+        let constant = if constant > 0.0 && constant.is_finite() {
+            constant
+        } else {
+            1.0
+        };
+
+        self.with_transformation(SymmetricLogarithm { constant })
+    }
+}
+
+#[test]
+fn symlog_coordinates() {
+    use super::DomainScale;
+
+    let symlog = SymLog::try_new(-1_000.0_f64, 1_000.0, 201).unwrap();
+
+    assert_eq!(symlog.domain_to_coordinate(-1_000.0), Some(0));
+    assert_eq!(symlog.domain_to_coordinate(0.0), Some(100));
+    assert_eq!(symlog.domain_to_coordinate(1_000.0), Some(200));
+    assert_eq!(symlog.domain_to_coordinate(1_001.0), None);
+
+    // symmetric around zero
+    let below = symlog.domain_to_coordinate(-10.0).unwrap();
+    let above = symlog.domain_to_coordinate(10.0).unwrap();
+    assert_eq!(100 - below, above - 100);
+
+    let domain = symlog.coordinate_to_domain(150).unwrap();
+    assert!((domain - (f64::sqrt(1_001.0) - 1.0)).abs() < 1e-9);
+}
+
+#[test]
+fn symlog_ticks() {
+    use super::IterableScale;
+
+    let symlog = SymLog::try_new(-1_000_i32, 100, 201)
+        .unwrap()
+        .constant(10.0);
+    let ticks: Vec<i32> = symlog.intervals(0).map(|(domain, _)| domain).collect();
+    assert_eq!(ticks, vec![-1_000, -100, -10, 0, 10, 100]);
+
+    let symlog = SymLog::try_new(50.0_f64, -50.0, 201).unwrap();
+    let ticks: Vec<f64> = symlog.intervals(4.0).map(|(domain, _)| domain).collect();
+    assert_eq!(
+        ticks,
+        vec![50.0, 10.0, 9.0, 5.0, 1.0, 0.0, -1.0, -5.0, -9.0, -10.0, -50.0]
+    );
+}
+
+#[test]
+fn symlog_tick_values() {
+    use super::Scale;
+
+    let symlog = SymLog::try_new(100.0_f64, -100.0, 201).unwrap();
+    let ticks: Vec<f64> = symlog
+        .tick_values()
+        .into_iter()
+        .map(|(domain, _)| domain)
+        .collect();
+    assert_eq!(
+        ticks,
+        vec![100.0, 80.0, 60.0, 40.0, 20.0, 0.0, -20.0, -40.0, -60.0, -80.0, -100.0]
+    );
+}
@@ -0,0 +1,159 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
+
+use super::{Result, Scale, ScaleError};
+
+/// Threshold scale, dividing the domain at explicit breakpoints
+///
+/// With `n` thresholds the range needs `n + 1` values: values below the first threshold
+/// map onto the first range value, values at or above the last threshold onto the last.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::scales::Threshold;
+///
+/// let grade = Threshold::try_new(vec![5.5, 8.0], vec!["fail", "pass", "distinction"]).unwrap();
+///
+/// assert_eq!(grade.map(5.5), Some(&"pass"));
+/// assert_eq!(grade.invert_extent(&"fail"), Some((None, Some(5.5))));
+/// ```
+#[derive(Debug)]
+pub struct Threshold<DT, R>
+where
+    DT: PartialOrd + Debug + Display + Copy,
+{
+    thresholds: Vec<DT>,
+    range: Vec<R>,
+}
+
+impl<DT, R> Threshold<DT, R>
+where
+    DT: PartialOrd + Debug + Display + Copy,
+{
+    pub fn try_new<I, J>(thresholds: I, range: J) -> Result<Self>
+    where
+        I: IntoIterator<Item = DT>,
+        J: IntoIterator<Item = R>,
+    {
+        let thresholds: Vec<DT> = thresholds.into_iter().collect();
+        if let Some(pair) = thresholds
+            .windows(2)
+            .find(|pair| pair[0].partial_cmp(&pair[1]) != Some(Ordering::Less))
+        {
+            return Err(ScaleError::InvalidDomain {
+                explain: format!(
+                    "thresholds must be strictly ascending, but {} is followed by {}",
+                    pair[0], pair[1]
+                ),
+            });
+        }
+
+        let range: Vec<R> = range.into_iter().collect();
+        if range.len() != thresholds.len() + 1 {
+            return Err(ScaleError::InvalidRange {
+                explain: format!(
+                    "{} thresholds need {} range values, not {}",
+                    thresholds.len(),
+                    thresholds.len() + 1,
+                    range.len()
+                ),
+            });
+        }
+
+        Ok(Self { thresholds, range })
+    }
+
+    /// The range value of the interval that contains the value,
+    /// or [None] when the value cannot be compared, like NaN
+    pub fn map(&self, value: DT) -> Option<&R> {
+        value.partial_cmp(&value)?;
+
+        self.range.get(
+            self.thresholds
+                .partition_point(|threshold| *threshold <= value),
+        )
+    }
+
+    pub fn thresholds(&self) -> &[DT] {
+        &self.thresholds
+    }
+
+    pub fn range(&self) -> &[R] {
+        &self.range
+    }
+
+    /// The interval of the domain that maps onto the first occurrence of a range value;
+    /// the first interval has no lower bound and the last has no upper bound
+    pub fn invert_extent(&self, value: &R) -> Option<(Option<DT>, Option<DT>)>
+    where
+        R: PartialEq,
+    {
+        self.range
+            .iter()
+            .position(|candidate| candidate == value)
+            .map(|index| {
+                (
+                    index.checked_sub(1).map(|before| self.thresholds[before]),
+                    self.thresholds.get(index).copied(),
+                )
+            })
+    }
+}
+
+impl<DT, R> Scale for Threshold<DT, R>
+where
+    DT: PartialOrd + Debug + Display + Copy,
+    R: Clone,
+{
+    type Domain = DT;
+    type Range = R;
+
+    /// The thresholds
+    fn domain_values(&self) -> Vec<DT> {
+        self.thresholds.clone()
+    }
+
+    fn range_values(&self) -> Vec<R> {
+        self.range.clone()
+    }
+
+    fn map_value(&self, value: &DT) -> Option<R> {
+        self.map(*value).cloned()
+    }
+
+    /// Each threshold, together with the range value of the interval it starts
+    fn tick_values(&self) -> Vec<(DT, R)> {
+        self.thresholds
+            .iter()
+            .copied()
+            .zip(self.range[1..].iter().cloned())
+            .collect()
+    }
+}
+
+#[test]
+fn threshold_intervals() {
+    let threshold =
+        Threshold::try_new([0, 10, 100], ["negative", "low", "medium", "high"]).unwrap();
+
+    assert_eq!(threshold.map(-5), Some(&"negative"));
+    assert_eq!(threshold.map(0), Some(&"low"));
+    assert_eq!(threshold.map(99), Some(&"medium"));
+    assert_eq!(threshold.map(100), Some(&"high"));
+
+    assert_eq!(threshold.invert_extent(&"negative"), Some((None, Some(0))));
+    assert_eq!(
+        threshold.invert_extent(&"medium"),
+        Some((Some(10), Some(100)))
+    );
+    assert_eq!(threshold.invert_extent(&"high"), Some((Some(100), None)));
+
+    assert!(Threshold::try_new([10, 0], ["a", "b", "c"]).is_err());
+    assert!(Threshold::try_new([0, 10], ["a", "b"]).is_err());
+    assert_eq!(
+        Threshold::try_new([0.5], ["a", "b"]).unwrap().map(f64::NAN),
+        None
+    );
+}
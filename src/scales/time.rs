@@ -0,0 +1,318 @@
+use crate::{TimeInterval, Timestamp, MILLIS_PER_DAY};
+
+use super::{DomainScale, IterableScale, Result, Scale, ScaleError};
+
+/// Time scale, mapping a range of [Timestamp]s onto a dimension
+///
+/// Its ticks land on calendar boundaries, with an interval that
+/// is chosen from the span of the domain and the dimension.
+#[derive(Debug)]
+pub struct Time {
+    start: Timestamp,
+    end: Timestamp,
+    dimension: usize,
+    min: Timestamp,
+    max: Timestamp,
+}
+
+pub type TimeIter = std::vec::IntoIter<(Timestamp, usize)>;
+
+/// Minimal distance in the dimension between two automatically chosen ticks
+const TICK_SPACING: usize = 100;
+
+const MILLIS_PER_YEAR: i64 = 365 * MILLIS_PER_DAY;
+
+const TICK_INTERVALS: [TimeInterval; 18] = [
+    TimeInterval::second(),
+    TimeInterval::second().every(5),
+    TimeInterval::second().every(15),
+    TimeInterval::second().every(30),
+    TimeInterval::minute(),
+    TimeInterval::minute().every(5),
+    TimeInterval::minute().every(15),
+    TimeInterval::minute().every(30),
+    TimeInterval::hour(),
+    TimeInterval::hour().every(3),
+    TimeInterval::hour().every(6),
+    TimeInterval::hour().every(12),
+    TimeInterval::day(),
+    TimeInterval::day().every(2),
+    TimeInterval::week(0),
+    TimeInterval::month(),
+    TimeInterval::month().every(3),
+    TimeInterval::year(),
+];
+
+/// Choose the calendar interval whose duration is closest to the target duration
+fn interval_for_duration(target: f64) -> TimeInterval {
+    let first = TICK_INTERVALS[0].duration() as f64;
+    let last = TICK_INTERVALS[TICK_INTERVALS.len() - 1].duration() as f64;
+
+    if target < first {
+        TimeInterval::millisecond().every(nice_step(target).max(1.0) as i64)
+    } else if target > last {
+        TimeInterval::year().every(nice_step(target / last).max(1.0) as i64)
+    } else {
+        let index = TICK_INTERVALS
+            .iter()
+            .position(|interval| interval.duration() as f64 > target)
+            .unwrap_or(TICK_INTERVALS.len() - 1);
+        let (before, after) = (TICK_INTERVALS[index - 1], TICK_INTERVALS[index]);
+        if target / (before.duration() as f64) < (after.duration() as f64) / target {
+            before
+        } else {
+            after
+        }
+    }
+}
+
+/// Choose the smallest calendar interval that is at least as long as the given duration
+fn interval_at_least(duration: i64) -> TimeInterval {
+    if duration < TICK_INTERVALS[0].duration() {
+        TimeInterval::millisecond().every(duration.max(1))
+    } else {
+        TICK_INTERVALS
+            .iter()
+            .copied()
+            .find(|interval| interval.duration() >= duration)
+            .unwrap_or_else(|| {
+                TimeInterval::year()
+                    .every(nice_step(duration as f64 / MILLIS_PER_YEAR as f64).max(1.0) as i64)
+            })
+    }
+}
+
+/// Round a positive number to 1, 2 or 5 times a power of ten
+fn nice_step(value: f64) -> f64 {
+    let power = 10_f64.powf(f64::floor(value.log10()));
+    let error = value / power;
+
+    power
+        * if error >= 7.5 {
+            10.0
+        } else if error >= 3.5 {
+            5.0
+        } else if error >= 1.5 {
+            2.0
+        } else {
+            1.0
+        }
+}
+
+impl Time {
+    pub fn try_new(start: Timestamp, end: Timestamp, dimension: usize) -> Result<Self> {
+        if dimension < 5 {
+            return Err(ScaleError::DimensionTooSmall);
+        }
+
+        let (min, max) = if start < end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        if max.millis().checked_sub(min.millis()).is_none() {
+            return Err(ScaleError::OutOfRange {
+                explain: format!(
+                    "Difference between {} and {} is out of range",
+                    min.millis(),
+                    max.millis()
+                ),
+            });
+        }
+
+        Ok(Self {
+            start,
+            end,
+            dimension,
+            min,
+            max,
+        })
+    }
+
+    /// Ticks at calendar boundaries, about one per 100 coordinates of the dimension
+    pub fn ticks(&self) -> TimeIter {
+        let count = usize::max(2, self.dimension / TICK_SPACING);
+        let span = (self.max - self.min) as f64;
+
+        self.ticks_every(interval_for_duration(span / count as f64))
+    }
+
+    /// Ticks at the boundaries of the given calendar interval
+    pub fn ticks_every(&self, interval: TimeInterval) -> TimeIter {
+        let mut ticks: Vec<(Timestamp, usize)> = interval
+            .range(
+                self.min,
+                Timestamp::from_millis(self.max.millis().saturating_add(1)),
+            )
+            .map(|tick| (tick, self.coordinate(tick)))
+            .collect();
+
+        if self.start > self.end {
+            ticks.reverse();
+        }
+
+        ticks.into_iter()
+    }
+
+    fn float_coordinate(&self, value: Timestamp) -> f64 {
+        let range = (self.end - self.start) as f64;
+
+        if range == 0.0 {
+            0.0
+        } else {
+            (value - self.start) as f64 / range * (self.dimension - 1) as f64
+        }
+    }
+
+    fn coordinate(&self, value: Timestamp) -> usize {
+        f64::round(self.float_coordinate(value)) as usize
+    }
+
+    fn float_coordinate_to_domain(&self, coordinate: f64) -> Timestamp {
+        let range = (self.end - self.start) as f64;
+        let offset = f64::round(range * coordinate / (self.dimension - 1) as f64);
+
+        Timestamp::from_millis(self.start.millis() + offset as i64)
+    }
+}
+
+impl DomainScale<Timestamp> for Time {
+    fn domain_to_float_coordinate(&self, value: Timestamp) -> Option<f64> {
+        if self.min <= value && value <= self.max {
+            Some(self.float_coordinate(value))
+        } else {
+            None
+        }
+    }
+
+    fn coordinate_to_domain(&self, coordinate: usize) -> Option<Timestamp> {
+        if coordinate < self.dimension {
+            Some(self.float_coordinate_to_domain(coordinate as f64))
+        } else {
+            None
+        }
+    }
+}
+
+impl IterableScale<Timestamp> for Time {
+    type Iter = TimeIter;
+
+    /// Every coordinate of the dimension together with its domain value
+    fn iter(&self) -> Self::Iter {
+        (0..self.dimension)
+            .filter_map(|coordinate| {
+                self.coordinate_to_domain(coordinate)
+                    .map(|domain| (domain, coordinate))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Ticks at the boundaries of the smallest calendar interval that spans
+    /// at least `step` milliseconds
+    fn intervals(&self, step: i64) -> Self::Iter {
+        self.ticks_every(interval_at_least(step.abs()))
+    }
+}
+
+impl Scale for Time {
+    type Domain = Timestamp;
+    type Range = f64;
+
+    fn domain_values(&self) -> Vec<Timestamp> {
+        vec![self.start, self.end]
+    }
+
+    fn range_values(&self) -> Vec<f64> {
+        vec![0.0, (self.dimension - 1) as f64]
+    }
+
+    fn map_value(&self, value: &Timestamp) -> Option<f64> {
+        self.domain_to_float_coordinate(*value)
+    }
+
+    fn invert_value(&self, coordinate: &f64) -> Option<Timestamp> {
+        if 0.0 <= *coordinate && *coordinate <= (self.dimension - 1) as f64 {
+            Some(self.float_coordinate_to_domain(*coordinate))
+        } else {
+            None
+        }
+    }
+
+    fn tick_values(&self) -> Vec<(Timestamp, f64)> {
+        self.ticks()
+            .map(|(tick, _)| (tick, self.float_coordinate(tick)))
+            .collect()
+    }
+}
+
+#[test]
+fn time_coordinates() {
+    let start = Timestamp::from_ymd(2020, 1, 1).unwrap();
+    let end = Timestamp::from_ymd(2020, 1, 11).unwrap();
+    let time = Time::try_new(start, end, 101).unwrap();
+
+    assert_eq!(time.domain_to_coordinate(start), Some(0));
+    assert_eq!(
+        time.domain_to_coordinate(Timestamp::from_ymd_hms(2020, 1, 6, 0, 0, 0).unwrap()),
+        Some(50)
+    );
+    assert_eq!(
+        time.domain_to_coordinate(Timestamp::from_ymd(2020, 1, 12).unwrap()),
+        None
+    );
+    assert_eq!(
+        time.coordinate_to_domain(10),
+        Timestamp::from_ymd(2020, 1, 2)
+    );
+}
+
+#[test]
+fn time_ticks_on_calendar_boundaries() {
+    use crate::MILLIS_PER_SECOND;
+
+    let time = Time::try_new(
+        Timestamp::from_ymd_hms(2019, 11, 15, 13, 0, 0).unwrap(),
+        Timestamp::from_ymd_hms(2021, 2, 3, 0, 0, 0).unwrap(),
+        600,
+    )
+    .unwrap();
+    let ticks: Vec<String> = time
+        .ticks()
+        .map(|(tick, _)| tick.to_string()[..10].to_owned())
+        .collect();
+    assert_eq!(
+        ticks,
+        vec![
+            "2020-01-01",
+            "2020-04-01",
+            "2020-07-01",
+            "2020-10-01",
+            "2021-01-01"
+        ]
+    );
+
+    let time = Time::try_new(
+        Timestamp::from_ymd_hms(2021, 3, 4, 5, 6, 7).unwrap(),
+        Timestamp::from_ymd_hms(2021, 3, 4, 6, 0, 0).unwrap(),
+        300,
+    )
+    .unwrap();
+    let ticks: Vec<(u32, u32)> = time
+        .intervals(600 * MILLIS_PER_SECOND)
+        .map(|(tick, _)| (tick.hour(), tick.minute()))
+        .collect();
+    assert_eq!(ticks, vec![(5, 15), (5, 30), (5, 45), (6, 0)]);
+
+    let days: Vec<u32> = Time::try_new(
+        Timestamp::from_ymd(2021, 1, 28).unwrap(),
+        Timestamp::from_ymd(2021, 2, 4).unwrap(),
+        300,
+    )
+    .unwrap()
+    .intervals(2 * MILLIS_PER_DAY)
+    .map(|(tick, _)| tick.day())
+    .collect();
+    assert_eq!(days, vec![29, 31, 1, 3]);
+}
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Units, Url,
+};
+
+/// Outline of its shapes, outside of which the elements that reference it are not painted
+#[xml_element("clipPath")]
+#[derive(Default)]
+pub struct ClipPath {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr(rename = "clipPathUnits")]
+    units: Option<Units>,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_multi_element]
+    items: Vec<XMLElement>,
+}
+
+global_attributes!(ClipPath);
+
+presentation_attributes!(ClipPath);
+
+has_children!(ClipPath);
+
+impl ClipPath {
+    pub fn new<D>(id: D) -> Self
+    where
+        D: Display,
+    {
+        Self::default().with_id(id)
+    }
+
+    /// Reference to the clip path, for a `clip-path` attribute
+    pub fn url(&self) -> Url {
+        Url::new(self.id.as_deref().unwrap_or_default())
+    }
+
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = Some(units);
+
+        self
+    }
+}
@@ -0,0 +1,782 @@
+use std::{
+    error::Error,
+    f64::consts::PI,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{ByteOrPercentage, Color, ColorName, Rgb};
+
+/// Colour in the sRGB space, with channels from 0 to 255 and an opacity from 0 to 1
+///
+/// This is the space through which the other spaces are converted into each other
+/// and into a [Color].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Srgb {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub opacity: f64,
+}
+
+/// Colour as hue in degrees, and saturation and lightness from 0 to 1
+///
+/// The hue of greys is not a number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+    pub opacity: f64,
+}
+
+/// Colour in the CIELAB space, with lightness from 0 to 100, relative to the D50 white point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    pub lightness: f64,
+    pub a: f64,
+    pub b: f64,
+    pub opacity: f64,
+}
+
+/// Colour in the polar form of CIELAB, with hue in degrees and luminance from 0 to 100
+///
+/// The hue of greys is not a number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hcl {
+    pub hue: f64,
+    pub chroma: f64,
+    pub luminance: f64,
+    pub opacity: f64,
+}
+
+/// Colour in Dave Green's cubehelix space, with hue in degrees and lightness from 0 to 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cubehelix {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+    pub opacity: f64,
+}
+
+#[derive(Debug)]
+pub struct ParseColorError {
+    explain: String,
+}
+
+/// Factor by which [Color::brighter] and [Color::darker] change the channels
+const DARKER: f64 = 0.7;
+
+// D50 white point and the constants of the CIELAB transfer function
+const XN: f64 = 0.96422;
+const YN: f64 = 1.0;
+const ZN: f64 = 0.82521;
+const T0: f64 = 4.0 / 29.0;
+const T1: f64 = 6.0 / 29.0;
+const T2: f64 = 3.0 * T1 * T1;
+const T3: f64 = T1 * T1 * T1;
+
+// Cubehelix matrix
+const A: f64 = -0.14861;
+const B: f64 = 1.78277;
+const C: f64 = -0.29227;
+const D: f64 = -0.90649;
+const E: f64 = 1.97294;
+const ED: f64 = E * D;
+const EB: f64 = E * B;
+const BC_DA: f64 = B * C - D * A;
+
+impl Srgb {
+    pub fn new(red: f64, green: f64, blue: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            opacity: 1.0,
+        }
+    }
+
+    /// From `0xRRGGBB`
+    pub fn from_hex(hex: u32) -> Self {
+        Self::new(
+            (hex >> 16 & 0xff) as f64,
+            (hex >> 8 & 0xff) as f64,
+            (hex & 0xff) as f64,
+        )
+    }
+
+    /// To `0xRRGGBB`, with the channels clamped to whole bytes
+    pub fn to_hex(&self) -> u32 {
+        (byte(self.red) as u32) << 16 | (byte(self.green) as u32) << 8 | byte(self.blue) as u32
+    }
+}
+
+impl Hsl {
+    pub fn new(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+            opacity: 1.0,
+        }
+    }
+}
+
+impl Lab {
+    pub fn new(lightness: f64, a: f64, b: f64) -> Self {
+        Self {
+            lightness,
+            a,
+            b,
+            opacity: 1.0,
+        }
+    }
+}
+
+impl Hcl {
+    pub fn new(hue: f64, chroma: f64, luminance: f64) -> Self {
+        Self {
+            hue,
+            chroma,
+            luminance,
+            opacity: 1.0,
+        }
+    }
+}
+
+impl Cubehelix {
+    pub fn new(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+            opacity: 1.0,
+        }
+    }
+}
+
+impl From<Srgb> for Hsl {
+    fn from(rgb: Srgb) -> Self {
+        let (red, green, blue) = (rgb.red / 255.0, rgb.green / 255.0, rgb.blue / 255.0);
+        let min = red.min(green).min(blue);
+        let max = red.max(green).max(blue);
+        let lightness = (max + min) / 2.0;
+        let mut saturation = max - min;
+
+        let hue = if saturation != 0.0 {
+            let hue = if red == max {
+                (green - blue) / saturation + if green < blue { 6.0 } else { 0.0 }
+            } else if green == max {
+                (blue - red) / saturation + 2.0
+            } else {
+                (red - green) / saturation + 4.0
+            };
+            saturation /= if lightness < 0.5 {
+                max + min
+            } else {
+                2.0 - max - min
+            };
+
+            hue * 60.0
+        } else {
+            // greys have no hue, and black and white have no saturation either
+            if lightness <= 0.0 || lightness >= 1.0 {
+                saturation = f64::NAN;
+            }
+
+            f64::NAN
+        };
+
+        Self {
+            hue,
+            saturation,
+            lightness,
+            opacity: rgb.opacity,
+        }
+    }
+}
+
+impl From<Hsl> for Srgb {
+    fn from(hsl: Hsl) -> Self {
+        let hue = hsl.hue.rem_euclid(360.0);
+        let saturation = if hue.is_nan() || hsl.saturation.is_nan() {
+            0.0
+        } else {
+            hsl.saturation
+        };
+        let lightness = hsl.lightness;
+        let m2 = lightness
+            + if lightness < 0.5 {
+                lightness
+            } else {
+                1.0 - lightness
+            } * saturation;
+        let m1 = 2.0 * lightness - m2;
+        let channel = |hue: f64| {
+            let hue = if hue.is_nan() { 0.0 } else { hue };
+            255.0
+                * if hue < 60.0 {
+                    m1 + (m2 - m1) * hue / 60.0
+                } else if hue < 180.0 {
+                    m2
+                } else if hue < 240.0 {
+                    m1 + (m2 - m1) * (240.0 - hue) / 60.0
+                } else {
+                    m1
+                }
+        };
+
+        Self {
+            red: channel(if hue >= 240.0 {
+                hue - 240.0
+            } else {
+                hue + 120.0
+            }),
+            green: channel(hue),
+            blue: channel(if hue < 120.0 {
+                hue + 240.0
+            } else {
+                hue - 120.0
+            }),
+            opacity: hsl.opacity,
+        }
+    }
+}
+
+impl From<Srgb> for Lab {
+    fn from(rgb: Srgb) -> Self {
+        let (red, green, blue) = (
+            rgb_to_linear(rgb.red),
+            rgb_to_linear(rgb.green),
+            rgb_to_linear(rgb.blue),
+        );
+        let y = xyz_to_lab((0.2225045 * red + 0.7168786 * green + 0.0606169 * blue) / YN);
+        let (x, z) = if red == green && green == blue {
+            (y, y)
+        } else {
+            (
+                xyz_to_lab((0.4360747 * red + 0.3850649 * green + 0.1430804 * blue) / XN),
+                xyz_to_lab((0.0139322 * red + 0.0971045 * green + 0.7141733 * blue) / ZN),
+            )
+        };
+
+        Self {
+            lightness: 116.0 * y - 16.0,
+            a: 500.0 * (x - y),
+            b: 200.0 * (y - z),
+            opacity: rgb.opacity,
+        }
+    }
+}
+
+impl From<Lab> for Srgb {
+    fn from(lab: Lab) -> Self {
+        let y = (lab.lightness + 16.0) / 116.0;
+        let x = if lab.a.is_nan() { y } else { y + lab.a / 500.0 };
+        let z = if lab.b.is_nan() { y } else { y - lab.b / 200.0 };
+        let (x, y, z) = (XN * lab_to_xyz(x), YN * lab_to_xyz(y), ZN * lab_to_xyz(z));
+
+        Self {
+            red: linear_to_rgb(3.1338561 * x - 1.6168667 * y - 0.4906146 * z),
+            green: linear_to_rgb(-0.9787684 * x + 1.9161415 * y + 0.033454 * z),
+            blue: linear_to_rgb(0.0719453 * x - 0.2289914 * y + 1.4052427 * z),
+            opacity: lab.opacity,
+        }
+    }
+}
+
+impl From<Lab> for Hcl {
+    fn from(lab: Lab) -> Self {
+        if lab.a == 0.0 && lab.b == 0.0 {
+            Self {
+                hue: f64::NAN,
+                chroma: if 0.0 < lab.lightness && lab.lightness < 100.0 {
+                    0.0
+                } else {
+                    f64::NAN
+                },
+                luminance: lab.lightness,
+                opacity: lab.opacity,
+            }
+        } else {
+            Self {
+                hue: lab.b.atan2(lab.a).to_degrees().rem_euclid(360.0),
+                chroma: lab.a.hypot(lab.b),
+                luminance: lab.lightness,
+                opacity: lab.opacity,
+            }
+        }
+    }
+}
+
+impl From<Hcl> for Lab {
+    fn from(hcl: Hcl) -> Self {
+        if hcl.hue.is_nan() {
+            Self {
+                lightness: hcl.luminance,
+                a: 0.0,
+                b: 0.0,
+                opacity: hcl.opacity,
+            }
+        } else {
+            let hue = hcl.hue.to_radians();
+
+            Self {
+                lightness: hcl.luminance,
+                a: hue.cos() * hcl.chroma,
+                b: hue.sin() * hcl.chroma,
+                opacity: hcl.opacity,
+            }
+        }
+    }
+}
+
+impl From<Srgb> for Hcl {
+    fn from(rgb: Srgb) -> Self {
+        Lab::from(rgb).into()
+    }
+}
+
+impl From<Hcl> for Srgb {
+    fn from(hcl: Hcl) -> Self {
+        Lab::from(hcl).into()
+    }
+}
+
+impl From<Srgb> for Cubehelix {
+    fn from(rgb: Srgb) -> Self {
+        let (red, green, blue) = (rgb.red / 255.0, rgb.green / 255.0, rgb.blue / 255.0);
+        let lightness = (BC_DA * blue + ED * red - EB * green) / (BC_DA + ED - EB);
+        let blue_lightness = blue - lightness;
+        let k = (E * (green - lightness) - C * blue_lightness) / D;
+        let saturation =
+            (k * k + blue_lightness * blue_lightness).sqrt() / (E * lightness * (1.0 - lightness));
+        let hue = if saturation != 0.0 && !saturation.is_nan() {
+            (k.atan2(blue_lightness).to_degrees() - 120.0).rem_euclid(360.0)
+        } else {
+            f64::NAN
+        };
+
+        Self {
+            hue,
+            saturation,
+            lightness,
+            opacity: rgb.opacity,
+        }
+    }
+}
+
+impl From<Cubehelix> for Srgb {
+    fn from(cubehelix: Cubehelix) -> Self {
+        let hue = if cubehelix.hue.is_nan() {
+            0.0
+        } else {
+            (cubehelix.hue + 120.0).to_radians()
+        };
+        let lightness = cubehelix.lightness;
+        let amplitude = if cubehelix.saturation.is_nan() {
+            0.0
+        } else {
+            cubehelix.saturation * lightness * (1.0 - lightness)
+        };
+        let (cos, sin) = (hue.cos(), hue.sin());
+
+        Self {
+            red: 255.0 * (lightness + amplitude * (A * cos + B * sin)),
+            green: 255.0 * (lightness + amplitude * (C * cos + D * sin)),
+            blue: 255.0 * (lightness + amplitude * (E * cos)),
+            opacity: cubehelix.opacity,
+        }
+    }
+}
+
+impl From<&Color> for Srgb {
+    fn from(color: &Color) -> Self {
+        match color {
+            Color::Name(name) => Srgb::from_hex(name.hex()),
+            Color::Hex(hex) if *hex < 0x01000000 => Srgb::from_hex(*hex),
+            Color::Hex(hex) => Srgb {
+                opacity: (hex & 0xff) as f64 / 255.0,
+                ..Srgb::from_hex(hex >> 8)
+            },
+            Color::Rgb(rgb) => Srgb {
+                red: rgb.red().to_byte_value(),
+                green: rgb.green().to_byte_value(),
+                blue: rgb.blue().to_byte_value(),
+                opacity: rgb.alpha().map_or(1.0, |alpha| alpha as f64 / 100.0),
+            },
+        }
+    }
+}
+
+impl From<Color> for Srgb {
+    fn from(color: Color) -> Self {
+        Srgb::from(&color)
+    }
+}
+
+impl From<Srgb> for Color {
+    /// Opaque colours become hexadecimal, translucent ones `rgb()` with alpha
+    fn from(rgb: Srgb) -> Self {
+        let opacity = if rgb.opacity.is_nan() {
+            1.0
+        } else {
+            rgb.opacity.clamp(0.0, 1.0)
+        };
+
+        if opacity >= 1.0 {
+            Color::Hex(rgb.to_hex())
+        } else {
+            Color::Rgb(
+                Rgb::new(
+                    ByteOrPercentage::Byte(byte(rgb.red)),
+                    ByteOrPercentage::Byte(byte(rgb.green)),
+                    ByteOrPercentage::Byte(byte(rgb.blue)),
+                )
+                .with_alpha((f64::round(opacity * 1000.0) / 10.0) as f32),
+            )
+        }
+    }
+}
+
+macro_rules! convert_through_srgb {
+    ($($space:ty),+) => {
+        $(
+            impl From<&Color> for $space {
+                fn from(color: &Color) -> Self {
+                    Srgb::from(color).into()
+                }
+            }
+
+            impl From<Color> for $space {
+                fn from(color: Color) -> Self {
+                    Srgb::from(&color).into()
+                }
+            }
+
+            impl From<$space> for Color {
+                fn from(space: $space) -> Self {
+                    Srgb::from(space).into()
+                }
+            }
+        )+
+    };
+}
+
+convert_through_srgb!(Hsl, Lab, Hcl, Cubehelix);
+
+impl Color {
+    /// Opacity from 0 (transparent) to 1 (opaque)
+    pub fn opacity(&self) -> f64 {
+        Srgb::from(self).opacity
+    }
+
+    /// The same colour with another opacity, from 0 (transparent) to 1 (opaque)
+    pub fn with_opacity(&self, opacity: f64) -> Color {
+        Srgb {
+            opacity,
+            ..Srgb::from(self)
+        }
+        .into()
+    }
+
+    /// A brighter colour; each step of `k` multiplies the channels by 1 / 0.7
+    pub fn brighter(&self, k: f64) -> Color {
+        self.scale_channels(DARKER.powf(-k))
+    }
+
+    /// A darker colour; each step of `k` multiplies the channels by 0.7
+    pub fn darker(&self, k: f64) -> Color {
+        self.scale_channels(DARKER.powf(k))
+    }
+
+    fn scale_channels(&self, factor: f64) -> Color {
+        let rgb = Srgb::from(self);
+
+        Srgb {
+            red: rgb.red * factor,
+            green: rgb.green * factor,
+            blue: rgb.blue * factor,
+            opacity: rgb.opacity,
+        }
+        .into()
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parse a named colour, `transparent`, hexadecimal `#rgb`, `#rgba`, `#rrggbb`
+    /// and `#rrggbbaa`, and the `rgb()`, `rgba()`, `hsl()` and `hsla()` functions
+    /// with either comma or space separated arguments
+    fn from_str(css: &str) -> Result<Self, Self::Err> {
+        let css = css.trim().to_ascii_lowercase();
+        let error = || ParseColorError {
+            explain: format!("'{}' is not a CSS colour", css),
+        };
+
+        if let Some(hex) = css.strip_prefix('#') {
+            parse_hex(hex).ok_or_else(error)
+        } else if let Some(arguments) = function_arguments(&css, "rgb") {
+            parse_rgb(&arguments).ok_or_else(error)
+        } else if let Some(arguments) = function_arguments(&css, "hsl") {
+            parse_hsl(&arguments).ok_or_else(error)
+        } else if css == "transparent" {
+            Ok(Srgb {
+                opacity: 0.0,
+                ..Srgb::new(0.0, 0.0, 0.0)
+            }
+            .into())
+        } else {
+            ColorName::from_name(&css)
+                .map(Color::Name)
+                .ok_or_else(error)
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid colour: {}", self.explain)
+    }
+}
+
+fn byte(channel: f64) -> u8 {
+    if channel.is_nan() {
+        0
+    } else {
+        f64::round(channel.clamp(0.0, 255.0)) as u8
+    }
+}
+
+fn rgb_to_linear(channel: f64) -> f64 {
+    let channel = channel / 255.0;
+
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_rgb(channel: f64) -> f64 {
+    255.0
+        * if channel <= 0.0031308 {
+            12.92 * channel
+        } else {
+            1.055 * channel.powf(1.0 / 2.4) - 0.055
+        }
+}
+
+fn xyz_to_lab(t: f64) -> f64 {
+    if t > T3 {
+        t.cbrt()
+    } else {
+        t / T2 + T0
+    }
+}
+
+fn lab_to_xyz(t: f64) -> f64 {
+    if t > T1 {
+        t * t * t
+    } else {
+        T2 * (t - T0)
+    }
+}
+
+/// The arguments of `name(...)` or `namea(...)`, separated by commas, slashes or spaces
+fn function_arguments<'css>(css: &'css str, name: &str) -> Option<Vec<&'css str>> {
+    let arguments = css.strip_prefix(name)?;
+    let arguments = arguments.strip_prefix('a').unwrap_or(arguments);
+    let arguments = arguments
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+
+    Some(
+        arguments
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|argument| !argument.is_empty())
+            .collect(),
+    )
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    // expand the short forms by repeating each digit
+    let hex = match hex.len() {
+        3 | 4 => hex.chars().flat_map(|c| [c, c]).collect(),
+        6 | 8 => hex.to_string(),
+        _ => return None,
+    };
+    let value = u32::from_str_radix(&hex, 16).ok()?;
+
+    Some(if hex.len() == 6 {
+        Color::Hex(value)
+    } else {
+        Srgb {
+            opacity: (value & 0xff) as f64 / 255.0,
+            ..Srgb::from_hex(value >> 8)
+        }
+        .into()
+    })
+}
+
+fn parse_rgb(arguments: &[&str]) -> Option<Color> {
+    let channel = |argument: &str| match argument.strip_suffix('%') {
+        Some(percentage) => percentage.parse().ok().map(ByteOrPercentage::percentage),
+        None => argument
+            .parse::<f64>()
+            .ok()
+            .map(|number| ByteOrPercentage::Byte(byte(number))),
+    };
+
+    match arguments {
+        [red, green, blue, alpha @ ..] if alpha.len() <= 1 => {
+            let rgb = Rgb::new(channel(red)?, channel(green)?, channel(blue)?);
+
+            Some(Color::Rgb(match alpha.first() {
+                Some(alpha) => rgb.with_alpha((parse_alpha(alpha)? * 100.0) as f32),
+                None => rgb,
+            }))
+        }
+        _ => None,
+    }
+}
+
+fn parse_hsl(arguments: &[&str]) -> Option<Color> {
+    let percentage = |argument: &str| -> Option<f64> {
+        argument
+            .strip_suffix('%')
+            .unwrap_or(argument)
+            .parse::<f64>()
+            .ok()
+            .map(|percentage| percentage / 100.0)
+    };
+
+    match arguments {
+        [hue, saturation, lightness, alpha @ ..] if alpha.len() <= 1 => Some(
+            Hsl {
+                hue: parse_hue(hue)?,
+                saturation: percentage(saturation)?,
+                lightness: percentage(lightness)?,
+                opacity: match alpha.first() {
+                    Some(alpha) => parse_alpha(alpha)?,
+                    None => 1.0,
+                },
+            }
+            .into(),
+        ),
+        _ => None,
+    }
+}
+
+/// Hue in degrees, from a number with an optional angle unit
+fn parse_hue(hue: &str) -> Option<f64> {
+    [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / PI),
+        ("turn", 360.0),
+        ("", 1.0),
+    ]
+    .into_iter()
+    .find_map(|(unit, degrees)| {
+        hue.strip_suffix(unit)
+            .and_then(|number| number.parse::<f64>().ok())
+            .map(|number| number * degrees)
+    })
+}
+
+/// Opacity from 0 to 1, from a number or a percentage
+fn parse_alpha(alpha: &str) -> Option<f64> {
+    match alpha.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f64>().ok().map(|p| p / 100.0),
+        None => alpha.parse::<f64>().ok(),
+    }
+    .map(|alpha| alpha.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 0.01,
+        "{} is not close to {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn color_spaces() {
+    let steel_blue = Color::Name(ColorName::SteelBlue);
+
+    let hsl = Hsl::from(&steel_blue);
+    assert_close(hsl.hue, 207.27);
+    assert_close(hsl.saturation, 0.44);
+    assert_close(hsl.lightness, 0.49);
+
+    let lab = Lab::from(&steel_blue);
+    assert_close(lab.lightness, 51.99);
+    assert_close(lab.a, -8.36);
+    assert_close(lab.b, -32.83);
+
+    let hcl = Hcl::from(&steel_blue);
+    assert_close(hcl.hue, 255.71);
+    assert_close(hcl.chroma, 33.88);
+
+    for color in [Color::from(hsl), Color::from(lab), Color::from(hcl)] {
+        assert_eq!(color, Color::Hex(0x4682b4));
+    }
+    assert_eq!(
+        Color::from(Cubehelix::from(&steel_blue)),
+        Color::Hex(0x4682b4)
+    );
+
+    let grey = Hsl::from(Srgb::new(128.0, 128.0, 128.0));
+    assert!(grey.hue.is_nan());
+    assert_eq!(Color::from(grey), Color::Hex(0x808080));
+}
+
+#[test]
+fn color_manipulation() {
+    let color = Color::Hex(0x336699);
+
+    assert_eq!(color.brighter(1.0), Color::Hex(0x4992db));
+    assert_eq!(color.darker(1.0), Color::Hex(0x24476b));
+    assert_eq!(color.darker(0.0), color);
+    assert_eq!(
+        color.with_opacity(0.5).to_string(),
+        "rgb( 51 102 153 / 50% )"
+    );
+    assert_eq!(color.with_opacity(0.5).opacity(), 0.5);
+}
+
+#[test]
+fn parse_css_colors() {
+    let parse = |css: &str| css.parse::<Color>().unwrap();
+
+    assert_eq!(parse("#1f77b4"), Color::Hex(0x1f77b4));
+    assert_eq!(parse(" #ABC "), Color::Hex(0xaabbcc));
+    assert_eq!(parse("#00ff0080").to_string(), "rgb( 0 255 0 / 50.2% )");
+    assert_eq!(
+        parse("CornflowerBlue"),
+        Color::Name(ColorName::CornflowerBlue)
+    );
+    assert_eq!(parse("hsl(120 50% 50%)"), Color::Hex(0x40bf40));
+    assert_eq!(parse("hsla(0.5turn, 100%, 25%, 1)"), Color::Hex(0x008080));
+    assert_eq!(parse("rgb(255, 0, 50%)").to_string(), "rgb( 255 0 50% )");
+    assert_eq!(
+        parse("rgba(10 20 30 / 25%)").to_string(),
+        "rgb( 10 20 30 / 25% )"
+    );
+    assert_eq!(parse("transparent").opacity(), 0.0);
+
+    assert!("#12345".parse::<Color>().is_err());
+    assert!("hsl(120 50%)".parse::<Color>().is_err());
+    assert!("notacolour".parse::<Color>().is_err());
+}
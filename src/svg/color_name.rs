@@ -0,0 +1,642 @@
+use std::fmt::Display;
+
+/// The 148 named colours of CSS
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorName {
+    AliceBlue,
+    AntiqueWhite,
+    Aqua,
+    Aquamarine,
+    Azure,
+    Beige,
+    Bisque,
+    Black,
+    BlanchedAlmond,
+    Blue,
+    BlueViolet,
+    Brown,
+    BurlyWood,
+    CadetBlue,
+    Chartreuse,
+    Chocolate,
+    Coral,
+    CornflowerBlue,
+    Cornsilk,
+    Crimson,
+    Cyan,
+    DarkBlue,
+    DarkCyan,
+    DarkGoldenRod,
+    DarkGray,
+    DarkGreen,
+    DarkGrey,
+    DarkKhaki,
+    DarkMagenta,
+    DarkOliveGreen,
+    DarkOrange,
+    DarkOrchid,
+    DarkRed,
+    DarkSalmon,
+    DarkSeaGreen,
+    DarkSlateBlue,
+    DarkSlateGray,
+    DarkSlateGrey,
+    DarkTurquoise,
+    DarkViolet,
+    DeepPink,
+    DeepSkyBlue,
+    DimGray,
+    DimGrey,
+    DodgerBlue,
+    FireBrick,
+    FloralWhite,
+    ForestGreen,
+    Fuchsia,
+    Gainsboro,
+    GhostWhite,
+    Gold,
+    GoldenRod,
+    Gray,
+    Green,
+    GreenYellow,
+    Grey,
+    HoneyDew,
+    HotPink,
+    IndianRed,
+    Indigo,
+    Ivory,
+    Khaki,
+    Lavender,
+    LavenderBlush,
+    LawnGreen,
+    LemonChiffon,
+    LightBlue,
+    LightCoral,
+    LightCyan,
+    LightGoldenRodYellow,
+    LightGray,
+    LightGreen,
+    LightGrey,
+    LightPink,
+    LightSalmon,
+    LightSeaGreen,
+    LightSkyBlue,
+    LightSlateGray,
+    LightSlateGrey,
+    LightSteelBlue,
+    LightYellow,
+    Lime,
+    LimeGreen,
+    Linen,
+    Magenta,
+    Maroon,
+    MediumAquamarine,
+    MediumBlue,
+    MediumOrchid,
+    MediumPurple,
+    MediumSeaGreen,
+    MediumSlateBlue,
+    MediumSpringGreen,
+    MediumTurquoise,
+    MediumVioletRed,
+    MidnightBlue,
+    MintCream,
+    MistyRose,
+    Moccasin,
+    NavajoWhite,
+    Navy,
+    OldLace,
+    Olive,
+    OliveDrab,
+    Orange,
+    OrangeRed,
+    Orchid,
+    PaleGoldenRod,
+    PaleGreen,
+    PaleTurquoise,
+    PaleVioletRed,
+    PapayaWhip,
+    PeachPuff,
+    Peru,
+    Pink,
+    Plum,
+    PowderBlue,
+    Purple,
+    RebeccaPurple,
+    Red,
+    RosyBrown,
+    RoyalBlue,
+    SaddleBrown,
+    Salmon,
+    SandyBrown,
+    SeaGreen,
+    SeaShell,
+    Sienna,
+    Silver,
+    SkyBlue,
+    SlateBlue,
+    SlateGray,
+    SlateGrey,
+    Snow,
+    SpringGreen,
+    SteelBlue,
+    Tan,
+    Teal,
+    Thistle,
+    Tomato,
+    Turquoise,
+    Violet,
+    Wheat,
+    White,
+    WhiteSmoke,
+    Yellow,
+    YellowGreen,
+}
+
+impl ColorName {
+    pub const ALL: [ColorName; 148] = [
+        ColorName::AliceBlue,
+        ColorName::AntiqueWhite,
+        ColorName::Aqua,
+        ColorName::Aquamarine,
+        ColorName::Azure,
+        ColorName::Beige,
+        ColorName::Bisque,
+        ColorName::Black,
+        ColorName::BlanchedAlmond,
+        ColorName::Blue,
+        ColorName::BlueViolet,
+        ColorName::Brown,
+        ColorName::BurlyWood,
+        ColorName::CadetBlue,
+        ColorName::Chartreuse,
+        ColorName::Chocolate,
+        ColorName::Coral,
+        ColorName::CornflowerBlue,
+        ColorName::Cornsilk,
+        ColorName::Crimson,
+        ColorName::Cyan,
+        ColorName::DarkBlue,
+        ColorName::DarkCyan,
+        ColorName::DarkGoldenRod,
+        ColorName::DarkGray,
+        ColorName::DarkGreen,
+        ColorName::DarkGrey,
+        ColorName::DarkKhaki,
+        ColorName::DarkMagenta,
+        ColorName::DarkOliveGreen,
+        ColorName::DarkOrange,
+        ColorName::DarkOrchid,
+        ColorName::DarkRed,
+        ColorName::DarkSalmon,
+        ColorName::DarkSeaGreen,
+        ColorName::DarkSlateBlue,
+        ColorName::DarkSlateGray,
+        ColorName::DarkSlateGrey,
+        ColorName::DarkTurquoise,
+        ColorName::DarkViolet,
+        ColorName::DeepPink,
+        ColorName::DeepSkyBlue,
+        ColorName::DimGray,
+        ColorName::DimGrey,
+        ColorName::DodgerBlue,
+        ColorName::FireBrick,
+        ColorName::FloralWhite,
+        ColorName::ForestGreen,
+        ColorName::Fuchsia,
+        ColorName::Gainsboro,
+        ColorName::GhostWhite,
+        ColorName::Gold,
+        ColorName::GoldenRod,
+        ColorName::Gray,
+        ColorName::Green,
+        ColorName::GreenYellow,
+        ColorName::Grey,
+        ColorName::HoneyDew,
+        ColorName::HotPink,
+        ColorName::IndianRed,
+        ColorName::Indigo,
+        ColorName::Ivory,
+        ColorName::Khaki,
+        ColorName::Lavender,
+        ColorName::LavenderBlush,
+        ColorName::LawnGreen,
+        ColorName::LemonChiffon,
+        ColorName::LightBlue,
+        ColorName::LightCoral,
+        ColorName::LightCyan,
+        ColorName::LightGoldenRodYellow,
+        ColorName::LightGray,
+        ColorName::LightGreen,
+        ColorName::LightGrey,
+        ColorName::LightPink,
+        ColorName::LightSalmon,
+        ColorName::LightSeaGreen,
+        ColorName::LightSkyBlue,
+        ColorName::LightSlateGray,
+        ColorName::LightSlateGrey,
+        ColorName::LightSteelBlue,
+        ColorName::LightYellow,
+        ColorName::Lime,
+        ColorName::LimeGreen,
+        ColorName::Linen,
+        ColorName::Magenta,
+        ColorName::Maroon,
+        ColorName::MediumAquamarine,
+        ColorName::MediumBlue,
+        ColorName::MediumOrchid,
+        ColorName::MediumPurple,
+        ColorName::MediumSeaGreen,
+        ColorName::MediumSlateBlue,
+        ColorName::MediumSpringGreen,
+        ColorName::MediumTurquoise,
+        ColorName::MediumVioletRed,
+        ColorName::MidnightBlue,
+        ColorName::MintCream,
+        ColorName::MistyRose,
+        ColorName::Moccasin,
+        ColorName::NavajoWhite,
+        ColorName::Navy,
+        ColorName::OldLace,
+        ColorName::Olive,
+        ColorName::OliveDrab,
+        ColorName::Orange,
+        ColorName::OrangeRed,
+        ColorName::Orchid,
+        ColorName::PaleGoldenRod,
+        ColorName::PaleGreen,
+        ColorName::PaleTurquoise,
+        ColorName::PaleVioletRed,
+        ColorName::PapayaWhip,
+        ColorName::PeachPuff,
+        ColorName::Peru,
+        ColorName::Pink,
+        ColorName::Plum,
+        ColorName::PowderBlue,
+        ColorName::Purple,
+        ColorName::RebeccaPurple,
+        ColorName::Red,
+        ColorName::RosyBrown,
+        ColorName::RoyalBlue,
+        ColorName::SaddleBrown,
+        ColorName::Salmon,
+        ColorName::SandyBrown,
+        ColorName::SeaGreen,
+        ColorName::SeaShell,
+        ColorName::Sienna,
+        ColorName::Silver,
+        ColorName::SkyBlue,
+        ColorName::SlateBlue,
+        ColorName::SlateGray,
+        ColorName::SlateGrey,
+        ColorName::Snow,
+        ColorName::SpringGreen,
+        ColorName::SteelBlue,
+        ColorName::Tan,
+        ColorName::Teal,
+        ColorName::Thistle,
+        ColorName::Tomato,
+        ColorName::Turquoise,
+        ColorName::Violet,
+        ColorName::Wheat,
+        ColorName::White,
+        ColorName::WhiteSmoke,
+        ColorName::Yellow,
+        ColorName::YellowGreen,
+    ];
+
+    /// The named colour, ignoring case, or [None] when CSS does not know the name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|color_name| color_name.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The lowercase name, as used in CSS
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorName::AliceBlue => "aliceblue",
+            ColorName::AntiqueWhite => "antiquewhite",
+            ColorName::Aqua => "aqua",
+            ColorName::Aquamarine => "aquamarine",
+            ColorName::Azure => "azure",
+            ColorName::Beige => "beige",
+            ColorName::Bisque => "bisque",
+            ColorName::Black => "black",
+            ColorName::BlanchedAlmond => "blanchedalmond",
+            ColorName::Blue => "blue",
+            ColorName::BlueViolet => "blueviolet",
+            ColorName::Brown => "brown",
+            ColorName::BurlyWood => "burlywood",
+            ColorName::CadetBlue => "cadetblue",
+            ColorName::Chartreuse => "chartreuse",
+            ColorName::Chocolate => "chocolate",
+            ColorName::Coral => "coral",
+            ColorName::CornflowerBlue => "cornflowerblue",
+            ColorName::Cornsilk => "cornsilk",
+            ColorName::Crimson => "crimson",
+            ColorName::Cyan => "cyan",
+            ColorName::DarkBlue => "darkblue",
+            ColorName::DarkCyan => "darkcyan",
+            ColorName::DarkGoldenRod => "darkgoldenrod",
+            ColorName::DarkGray => "darkgray",
+            ColorName::DarkGreen => "darkgreen",
+            ColorName::DarkGrey => "darkgrey",
+            ColorName::DarkKhaki => "darkkhaki",
+            ColorName::DarkMagenta => "darkmagenta",
+            ColorName::DarkOliveGreen => "darkolivegreen",
+            ColorName::DarkOrange => "darkorange",
+            ColorName::DarkOrchid => "darkorchid",
+            ColorName::DarkRed => "darkred",
+            ColorName::DarkSalmon => "darksalmon",
+            ColorName::DarkSeaGreen => "darkseagreen",
+            ColorName::DarkSlateBlue => "darkslateblue",
+            ColorName::DarkSlateGray => "darkslategray",
+            ColorName::DarkSlateGrey => "darkslategrey",
+            ColorName::DarkTurquoise => "darkturquoise",
+            ColorName::DarkViolet => "darkviolet",
+            ColorName::DeepPink => "deeppink",
+            ColorName::DeepSkyBlue => "deepskyblue",
+            ColorName::DimGray => "dimgray",
+            ColorName::DimGrey => "dimgrey",
+            ColorName::DodgerBlue => "dodgerblue",
+            ColorName::FireBrick => "firebrick",
+            ColorName::FloralWhite => "floralwhite",
+            ColorName::ForestGreen => "forestgreen",
+            ColorName::Fuchsia => "fuchsia",
+            ColorName::Gainsboro => "gainsboro",
+            ColorName::GhostWhite => "ghostwhite",
+            ColorName::Gold => "gold",
+            ColorName::GoldenRod => "goldenrod",
+            ColorName::Gray => "gray",
+            ColorName::Green => "green",
+            ColorName::GreenYellow => "greenyellow",
+            ColorName::Grey => "grey",
+            ColorName::HoneyDew => "honeydew",
+            ColorName::HotPink => "hotpink",
+            ColorName::IndianRed => "indianred",
+            ColorName::Indigo => "indigo",
+            ColorName::Ivory => "ivory",
+            ColorName::Khaki => "khaki",
+            ColorName::Lavender => "lavender",
+            ColorName::LavenderBlush => "lavenderblush",
+            ColorName::LawnGreen => "lawngreen",
+            ColorName::LemonChiffon => "lemonchiffon",
+            ColorName::LightBlue => "lightblue",
+            ColorName::LightCoral => "lightcoral",
+            ColorName::LightCyan => "lightcyan",
+            ColorName::LightGoldenRodYellow => "lightgoldenrodyellow",
+            ColorName::LightGray => "lightgray",
+            ColorName::LightGreen => "lightgreen",
+            ColorName::LightGrey => "lightgrey",
+            ColorName::LightPink => "lightpink",
+            ColorName::LightSalmon => "lightsalmon",
+            ColorName::LightSeaGreen => "lightseagreen",
+            ColorName::LightSkyBlue => "lightskyblue",
+            ColorName::LightSlateGray => "lightslategray",
+            ColorName::LightSlateGrey => "lightslategrey",
+            ColorName::LightSteelBlue => "lightsteelblue",
+            ColorName::LightYellow => "lightyellow",
+            ColorName::Lime => "lime",
+            ColorName::LimeGreen => "limegreen",
+            ColorName::Linen => "linen",
+            ColorName::Magenta => "magenta",
+            ColorName::Maroon => "maroon",
+            ColorName::MediumAquamarine => "mediumaquamarine",
+            ColorName::MediumBlue => "mediumblue",
+            ColorName::MediumOrchid => "mediumorchid",
+            ColorName::MediumPurple => "mediumpurple",
+            ColorName::MediumSeaGreen => "mediumseagreen",
+            ColorName::MediumSlateBlue => "mediumslateblue",
+            ColorName::MediumSpringGreen => "mediumspringgreen",
+            ColorName::MediumTurquoise => "mediumturquoise",
+            ColorName::MediumVioletRed => "mediumvioletred",
+            ColorName::MidnightBlue => "midnightblue",
+            ColorName::MintCream => "mintcream",
+            ColorName::MistyRose => "mistyrose",
+            ColorName::Moccasin => "moccasin",
+            ColorName::NavajoWhite => "navajowhite",
+            ColorName::Navy => "navy",
+            ColorName::OldLace => "oldlace",
+            ColorName::Olive => "olive",
+            ColorName::OliveDrab => "olivedrab",
+            ColorName::Orange => "orange",
+            ColorName::OrangeRed => "orangered",
+            ColorName::Orchid => "orchid",
+            ColorName::PaleGoldenRod => "palegoldenrod",
+            ColorName::PaleGreen => "palegreen",
+            ColorName::PaleTurquoise => "paleturquoise",
+            ColorName::PaleVioletRed => "palevioletred",
+            ColorName::PapayaWhip => "papayawhip",
+            ColorName::PeachPuff => "peachpuff",
+            ColorName::Peru => "peru",
+            ColorName::Pink => "pink",
+            ColorName::Plum => "plum",
+            ColorName::PowderBlue => "powderblue",
+            ColorName::Purple => "purple",
+            ColorName::RebeccaPurple => "rebeccapurple",
+            ColorName::Red => "red",
+            ColorName::RosyBrown => "rosybrown",
+            ColorName::RoyalBlue => "royalblue",
+            ColorName::SaddleBrown => "saddlebrown",
+            ColorName::Salmon => "salmon",
+            ColorName::SandyBrown => "sandybrown",
+            ColorName::SeaGreen => "seagreen",
+            ColorName::SeaShell => "seashell",
+            ColorName::Sienna => "sienna",
+            ColorName::Silver => "silver",
+            ColorName::SkyBlue => "skyblue",
+            ColorName::SlateBlue => "slateblue",
+            ColorName::SlateGray => "slategray",
+            ColorName::SlateGrey => "slategrey",
+            ColorName::Snow => "snow",
+            ColorName::SpringGreen => "springgreen",
+            ColorName::SteelBlue => "steelblue",
+            ColorName::Tan => "tan",
+            ColorName::Teal => "teal",
+            ColorName::Thistle => "thistle",
+            ColorName::Tomato => "tomato",
+            ColorName::Turquoise => "turquoise",
+            ColorName::Violet => "violet",
+            ColorName::Wheat => "wheat",
+            ColorName::White => "white",
+            ColorName::WhiteSmoke => "whitesmoke",
+            ColorName::Yellow => "yellow",
+            ColorName::YellowGreen => "yellowgreen",
+        }
+    }
+
+    /// The colour as `0xRRGGBB`
+    pub fn hex(&self) -> u32 {
+        match self {
+            ColorName::AliceBlue => 0xf0f8ff,
+            ColorName::AntiqueWhite => 0xfaebd7,
+            ColorName::Aqua => 0x00ffff,
+            ColorName::Aquamarine => 0x7fffd4,
+            ColorName::Azure => 0xf0ffff,
+            ColorName::Beige => 0xf5f5dc,
+            ColorName::Bisque => 0xffe4c4,
+            ColorName::Black => 0x000000,
+            ColorName::BlanchedAlmond => 0xffebcd,
+            ColorName::Blue => 0x0000ff,
+            ColorName::BlueViolet => 0x8a2be2,
+            ColorName::Brown => 0xa52a2a,
+            ColorName::BurlyWood => 0xdeb887,
+            ColorName::CadetBlue => 0x5f9ea0,
+            ColorName::Chartreuse => 0x7fff00,
+            ColorName::Chocolate => 0xd2691e,
+            ColorName::Coral => 0xff7f50,
+            ColorName::CornflowerBlue => 0x6495ed,
+            ColorName::Cornsilk => 0xfff8dc,
+            ColorName::Crimson => 0xdc143c,
+            ColorName::Cyan => 0x00ffff,
+            ColorName::DarkBlue => 0x00008b,
+            ColorName::DarkCyan => 0x008b8b,
+            ColorName::DarkGoldenRod => 0xb8860b,
+            ColorName::DarkGray => 0xa9a9a9,
+            ColorName::DarkGreen => 0x006400,
+            ColorName::DarkGrey => 0xa9a9a9,
+            ColorName::DarkKhaki => 0xbdb76b,
+            ColorName::DarkMagenta => 0x8b008b,
+            ColorName::DarkOliveGreen => 0x556b2f,
+            ColorName::DarkOrange => 0xff8c00,
+            ColorName::DarkOrchid => 0x9932cc,
+            ColorName::DarkRed => 0x8b0000,
+            ColorName::DarkSalmon => 0xe9967a,
+            ColorName::DarkSeaGreen => 0x8fbc8f,
+            ColorName::DarkSlateBlue => 0x483d8b,
+            ColorName::DarkSlateGray => 0x2f4f4f,
+            ColorName::DarkSlateGrey => 0x2f4f4f,
+            ColorName::DarkTurquoise => 0x00ced1,
+            ColorName::DarkViolet => 0x9400d3,
+            ColorName::DeepPink => 0xff1493,
+            ColorName::DeepSkyBlue => 0x00bfff,
+            ColorName::DimGray => 0x696969,
+            ColorName::DimGrey => 0x696969,
+            ColorName::DodgerBlue => 0x1e90ff,
+            ColorName::FireBrick => 0xb22222,
+            ColorName::FloralWhite => 0xfffaf0,
+            ColorName::ForestGreen => 0x228b22,
+            ColorName::Fuchsia => 0xff00ff,
+            ColorName::Gainsboro => 0xdcdcdc,
+            ColorName::GhostWhite => 0xf8f8ff,
+            ColorName::Gold => 0xffd700,
+            ColorName::GoldenRod => 0xdaa520,
+            ColorName::Gray => 0x808080,
+            ColorName::Green => 0x008000,
+            ColorName::GreenYellow => 0xadff2f,
+            ColorName::Grey => 0x808080,
+            ColorName::HoneyDew => 0xf0fff0,
+            ColorName::HotPink => 0xff69b4,
+            ColorName::IndianRed => 0xcd5c5c,
+            ColorName::Indigo => 0x4b0082,
+            ColorName::Ivory => 0xfffff0,
+            ColorName::Khaki => 0xf0e68c,
+            ColorName::Lavender => 0xe6e6fa,
+            ColorName::LavenderBlush => 0xfff0f5,
+            ColorName::LawnGreen => 0x7cfc00,
+            ColorName::LemonChiffon => 0xfffacd,
+            ColorName::LightBlue => 0xadd8e6,
+            ColorName::LightCoral => 0xf08080,
+            ColorName::LightCyan => 0xe0ffff,
+            ColorName::LightGoldenRodYellow => 0xfafad2,
+            ColorName::LightGray => 0xd3d3d3,
+            ColorName::LightGreen => 0x90ee90,
+            ColorName::LightGrey => 0xd3d3d3,
+            ColorName::LightPink => 0xffb6c1,
+            ColorName::LightSalmon => 0xffa07a,
+            ColorName::LightSeaGreen => 0x20b2aa,
+            ColorName::LightSkyBlue => 0x87cefa,
+            ColorName::LightSlateGray => 0x778899,
+            ColorName::LightSlateGrey => 0x778899,
+            ColorName::LightSteelBlue => 0xb0c4de,
+            ColorName::LightYellow => 0xffffe0,
+            ColorName::Lime => 0x00ff00,
+            ColorName::LimeGreen => 0x32cd32,
+            ColorName::Linen => 0xfaf0e6,
+            ColorName::Magenta => 0xff00ff,
+            ColorName::Maroon => 0x800000,
+            ColorName::MediumAquamarine => 0x66cdaa,
+            ColorName::MediumBlue => 0x0000cd,
+            ColorName::MediumOrchid => 0xba55d3,
+            ColorName::MediumPurple => 0x9370db,
+            ColorName::MediumSeaGreen => 0x3cb371,
+            ColorName::MediumSlateBlue => 0x7b68ee,
+            ColorName::MediumSpringGreen => 0x00fa9a,
+            ColorName::MediumTurquoise => 0x48d1cc,
+            ColorName::MediumVioletRed => 0xc71585,
+            ColorName::MidnightBlue => 0x191970,
+            ColorName::MintCream => 0xf5fffa,
+            ColorName::MistyRose => 0xffe4e1,
+            ColorName::Moccasin => 0xffe4b5,
+            ColorName::NavajoWhite => 0xffdead,
+            ColorName::Navy => 0x000080,
+            ColorName::OldLace => 0xfdf5e6,
+            ColorName::Olive => 0x808000,
+            ColorName::OliveDrab => 0x6b8e23,
+            ColorName::Orange => 0xffa500,
+            ColorName::OrangeRed => 0xff4500,
+            ColorName::Orchid => 0xda70d6,
+            ColorName::PaleGoldenRod => 0xeee8aa,
+            ColorName::PaleGreen => 0x98fb98,
+            ColorName::PaleTurquoise => 0xafeeee,
+            ColorName::PaleVioletRed => 0xdb7093,
+            ColorName::PapayaWhip => 0xffefd5,
+            ColorName::PeachPuff => 0xffdab9,
+            ColorName::Peru => 0xcd853f,
+            ColorName::Pink => 0xffc0cb,
+            ColorName::Plum => 0xdda0dd,
+            ColorName::PowderBlue => 0xb0e0e6,
+            ColorName::Purple => 0x800080,
+            ColorName::RebeccaPurple => 0x663399,
+            ColorName::Red => 0xff0000,
+            ColorName::RosyBrown => 0xbc8f8f,
+            ColorName::RoyalBlue => 0x4169e1,
+            ColorName::SaddleBrown => 0x8b4513,
+            ColorName::Salmon => 0xfa8072,
+            ColorName::SandyBrown => 0xf4a460,
+            ColorName::SeaGreen => 0x2e8b57,
+            ColorName::SeaShell => 0xfff5ee,
+            ColorName::Sienna => 0xa0522d,
+            ColorName::Silver => 0xc0c0c0,
+            ColorName::SkyBlue => 0x87ceeb,
+            ColorName::SlateBlue => 0x6a5acd,
+            ColorName::SlateGray => 0x708090,
+            ColorName::SlateGrey => 0x708090,
+            ColorName::Snow => 0xfffafa,
+            ColorName::SpringGreen => 0x00ff7f,
+            ColorName::SteelBlue => 0x4682b4,
+            ColorName::Tan => 0xd2b48c,
+            ColorName::Teal => 0x008080,
+            ColorName::Thistle => 0xd8bfd8,
+            ColorName::Tomato => 0xff6347,
+            ColorName::Turquoise => 0x40e0d0,
+            ColorName::Violet => 0xee82ee,
+            ColorName::Wheat => 0xf5deb3,
+            ColorName::White => 0xffffff,
+            ColorName::WhiteSmoke => 0xf5f5f5,
+            ColorName::Yellow => 0xffff00,
+            ColorName::YellowGreen => 0x9acd32,
+        }
+    }
+}
+
+impl Display for ColorName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[test]
+fn color_names() {
+    assert_eq!(
+        ColorName::from_name("RebeccaPurple"),
+        Some(ColorName::RebeccaPurple)
+    );
+    assert_eq!(
+        ColorName::from_name("lightgoldenrodyellow").map(|name| name.hex()),
+        Some(0xfafad2)
+    );
+    assert_eq!(ColorName::from_name("transparent"), None);
+    assert_eq!(ColorName::DarkSlateGrey.to_string(), "darkslategrey");
+}
@@ -0,0 +1,145 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+/// Container for elements that are only rendered when referenced, like gradients and clip paths
+#[xml_element("defs")]
+#[derive(Default)]
+pub struct Defs {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_multi_element]
+    items: Vec<XMLElement>,
+}
+
+global_attributes!(Defs);
+
+presentation_attributes!(Defs);
+
+has_children!(Defs);
+
+/// The coordinate system of the attributes of a gradient, pattern, clip path or mask
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Units {
+    /// The user coordinates of the element that references it
+    UserSpaceOnUse,
+    /// Fractions of the bounding box of the element that references it
+    ObjectBoundingBox,
+}
+
+impl Display for Units {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Units::UserSpaceOnUse => "userSpaceOnUse",
+            Units::ObjectBoundingBox => "objectBoundingBox",
+        })
+    }
+}
+
+#[test]
+fn reference_definitions() {
+    use crate::{
+        ClipPath, Color, ColorName, Document, Group, LengthOrPercentage, LinearGradient, Mask,
+        Paint, Pattern, RadialGradient, Stop,
+    };
+
+    let fade = LinearGradient::new("fade")
+        .vector(0.into(), 0.into(), 0.into(), 1.into())
+        .stop(Stop::new(0.into(), Color::Hex(0x4682b4)))
+        .stop(Stop::new(LengthOrPercentage::HUNDRED_PERCENT, Color::Hex(0x4682b4)).opacity(0.2));
+    let glow = RadialGradient::new("glow")
+        .circle("50%".into(), "50%".into(), "50%".into())
+        .units(Units::ObjectBoundingBox)
+        .stop(Stop::new(0.into(), Color::Name(ColorName::White)));
+    let mut hatch = Pattern::new("hatch", 4.into(), 4.into()).units(Units::UserSpaceOnUse);
+    hatch.add(crate::line!((0, 4), (4, 0), stroke: ColorName::Black));
+    let mut chart_area = ClipPath::new("chart-area");
+    chart_area.add(crate::rect!((30, 20), (100, 50)));
+    let mut spotlight = Mask::new("spotlight");
+    spotlight.add(crate::circle!((50, 50), 25, fill: ColorName::White));
+
+    let (fade_url, glow_url, hatch_url) = (fade.url(), glow.url(), hatch.url());
+    let (clip_url, mask_url) = (chart_area.url(), spotlight.url());
+    let mut defs = Defs::default();
+    defs.add(fade);
+    defs.add(glow);
+    defs.add(hatch);
+    defs.add(chart_area);
+    defs.add(spotlight);
+
+    let svg = crate::svg!(
+        200,
+        100,
+        [
+            defs,
+            crate::group!(clip_path: clip_url, mask: mask_url, [
+                crate::rect!((0, 0), (10, 10), fill: fade_url, stroke: Paint::None),
+                crate::rect!((20, 0), (10, 10), fill: hatch_url, stroke: glow_url)
+            ])
+        ]
+    )
+    .to_string();
+
+    assert!(svg.contains(r#"<linearGradient id="fade" x1="0" y1="0" x2="0" y2="1">"#));
+    assert!(svg.contains(r##"<stop offset="100%" stop-color="#4682b4" stop-opacity="0.2"/>"##));
+    assert!(svg.contains(
+        r#"<radialGradient id="glow" cx="50%" cy="50%" r="50%" gradientUnits="objectBoundingBox">"#
+    ));
+    assert!(
+        svg.contains(r#"<pattern id="hatch" width="4" height="4" patternUnits="userSpaceOnUse">"#)
+    );
+    assert!(svg.contains(r#"<line x1="0" y1="4" x2="4" y2="0" stroke="black"/>"#));
+    assert!(svg.contains(r#"<clipPath id="chart-area">"#));
+    assert!(svg.contains(r#"<circle cx="50" cy="50" r="25" fill="white"/>"#));
+    assert!(svg.contains(r#"<g clip-path="url(#chart-area)" mask="url(#spotlight)">"#));
+    assert!(svg
+        .contains(r#"<rect x="0" y="0" width="10" height="10" fill="url(#fade)" stroke="none"/>"#));
+    assert!(svg.contains(
+        r#"<rect x="20" y="0" width="10" height="10" fill="url(#hatch)" stroke="url(#glow)"/>"#
+    ));
+}
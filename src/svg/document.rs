@@ -3,6 +3,10 @@ use std::fmt::Display;
 use simple_xml_serialize::XMLElement;
 use simple_xml_serialize_macro::xml_element;
 
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
 const XMLNS: &str = "http://www.w3.org/2000/svg";
 // const XLINK: &str = "xmlns:xlink=\"http://www.w3.org/1999/xlink\"";
 
@@ -25,7 +29,40 @@ pub struct Document {
     xmlns: &'static str,
 
     #[sxs_type_element]
-    style: Option<CSS>,
+    stylesheet: Option<CSS>,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
 
     #[sxs_type_multi_element]
     items: Vec<XMLElement>,
@@ -40,18 +77,16 @@ struct CSS {
 
 global_attributes!(Document);
 
+presentation_attributes!(Document);
+
 has_children!(Document);
 
 impl Document {
     pub fn new(width: u16, height: u16) -> Self {
         Self {
-            id: None,
-            class: None,
-            meta: None,
             view_box: format!("0 0 {} {}", width, height),
             xmlns: XMLNS,
-            style: None,
-            items: vec![],
+            ..Default::default()
         }
     }
 
@@ -59,7 +94,7 @@ impl Document {
     where
         CT: Display,
     {
-        self.style = Some(CSS {
+        self.stylesheet = Some(CSS {
             text: format!("{}", style),
         });
 
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+#[xml_element("ellipse")]
+#[derive(Default)]
+pub struct Ellipse {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    pub cx: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    pub cy: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    pub rx: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    pub ry: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+}
+
+global_attributes!(Ellipse);
+
+presentation_attributes!(Ellipse);
+
+impl Ellipse {
+    pub fn new(
+        cx: LengthOrPercentage,
+        cy: LengthOrPercentage,
+        rx: LengthOrPercentage,
+        ry: LengthOrPercentage,
+    ) -> Self {
+        Self {
+            cx,
+            cy,
+            rx,
+            ry,
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{Color, LengthOrPercentage, Units, Url};
+
+/// Colour at an offset along a gradient
+#[xml_element("stop")]
+#[derive(Default)]
+pub struct Stop {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    offset: LengthOrPercentage,
+
+    #[sxs_type_attr(rename = "stop-color")]
+    color: Option<Color>,
+
+    #[sxs_type_attr(rename = "stop-opacity")]
+    opacity: Option<f64>,
+}
+
+global_attributes!(Stop);
+
+impl Stop {
+    /// The offset is a number from 0 to 1, or a percentage
+    pub fn new(offset: LengthOrPercentage, color: Color) -> Self {
+        Self {
+            offset,
+            color: Some(color),
+            ..Self::default()
+        }
+    }
+
+    /// Opacity from 0 to 1; values outside of that range are clamped
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity.clamp(0.0, 1.0));
+
+        self
+    }
+}
+
+/// Gradient along the vector from `(x1, y1)` to `(x2, y2)`, which runs from left to right by default
+///
+/// # Example
+/// ```rust
+/// # use d3rs::{Color, LengthOrPercentage, LinearGradient, Paint, Stop};
+///
+/// let fade = LinearGradient::new("fade")
+///     .stop(Stop::new(LengthOrPercentage::from(0), Color::Hex(0x4682b4)))
+///     .stop(Stop::new(LengthOrPercentage::from(1), Color::Hex(0x4682b4)).opacity(0.0));
+///
+/// assert_eq!(Paint::from(fade.url()).to_string(), "url(#fade)");
+/// ```
+#[xml_element("linearGradient")]
+#[derive(Default)]
+pub struct LinearGradient {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    x1: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    y1: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    x2: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    y2: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "gradientUnits")]
+    units: Option<Units>,
+
+    #[sxs_type_multi_element]
+    stops: Vec<Stop>,
+}
+
+global_attributes!(LinearGradient);
+
+impl LinearGradient {
+    pub fn new<D>(id: D) -> Self
+    where
+        D: Display,
+    {
+        Self::default().with_id(id)
+    }
+
+    /// Reference to the gradient, for a fill or a stroke
+    pub fn url(&self) -> Url {
+        Url::new(self.id.as_deref().unwrap_or_default())
+    }
+
+    pub fn vector(
+        mut self,
+        x1: LengthOrPercentage,
+        y1: LengthOrPercentage,
+        x2: LengthOrPercentage,
+        y2: LengthOrPercentage,
+    ) -> Self {
+        self.x1 = Some(x1);
+        self.y1 = Some(y1);
+        self.x2 = Some(x2);
+        self.y2 = Some(y2);
+
+        self
+    }
+
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = Some(units);
+
+        self
+    }
+
+    pub fn stop(mut self, stop: Stop) -> Self {
+        self.stops.push(stop);
+
+        self
+    }
+}
+
+/// Gradient from the focal point `(fx, fy)` to the circle around `(cx, cy)` with radius `r`
+#[xml_element("radialGradient")]
+#[derive(Default)]
+pub struct RadialGradient {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    cx: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    cy: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    r: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    fx: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    fy: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "gradientUnits")]
+    units: Option<Units>,
+
+    #[sxs_type_multi_element]
+    stops: Vec<Stop>,
+}
+
+global_attributes!(RadialGradient);
+
+impl RadialGradient {
+    pub fn new<D>(id: D) -> Self
+    where
+        D: Display,
+    {
+        Self::default().with_id(id)
+    }
+
+    /// Reference to the gradient, for a fill or a stroke
+    pub fn url(&self) -> Url {
+        Url::new(self.id.as_deref().unwrap_or_default())
+    }
+
+    pub fn circle(
+        mut self,
+        cx: LengthOrPercentage,
+        cy: LengthOrPercentage,
+        r: LengthOrPercentage,
+    ) -> Self {
+        self.cx = Some(cx);
+        self.cy = Some(cy);
+        self.r = Some(r);
+
+        self
+    }
+
+    /// The point where the gradient starts, which is the centre of the circle by default
+    pub fn focal_point(mut self, fx: LengthOrPercentage, fy: LengthOrPercentage) -> Self {
+        self.fx = Some(fx);
+        self.fy = Some(fy);
+
+        self
+    }
+
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = Some(units);
+
+        self
+    }
+
+    pub fn stop(mut self, stop: Stop) -> Self {
+        self.stops.push(stop);
+
+        self
+    }
+}
@@ -3,6 +3,10 @@ use std::fmt::Display;
 use simple_xml_serialize::XMLElement;
 use simple_xml_serialize_macro::xml_element;
 
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
 #[xml_element("g")]
 #[derive(Default)]
 pub struct Group {
@@ -15,10 +19,45 @@ pub struct Group {
     #[sxs_type_attr(rename = "data-meta")]
     meta: Option<String>,
 
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
     #[sxs_type_multi_element]
     items: Vec<XMLElement>,
 }
 
 global_attributes!(Group);
 
+presentation_attributes!(Group);
+
 has_children!(Group);
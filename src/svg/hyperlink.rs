@@ -3,6 +3,10 @@ use std::fmt::Display;
 use simple_xml_serialize::XMLElement;
 use simple_xml_serialize_macro::xml_element;
 
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
 #[xml_element("a")]
 #[derive(Default)]
 pub struct Hyperlink {
@@ -21,6 +25,39 @@ pub struct Hyperlink {
     #[sxs_type_attr]
     target: Option<HyperlinkTarget>,
 
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
     #[sxs_type_multi_element]
     items: Vec<XMLElement>,
 }
@@ -35,6 +72,8 @@ pub enum HyperlinkTarget {
 
 global_attributes!(Hyperlink);
 
+presentation_attributes!(Hyperlink);
+
 has_children!(Hyperlink);
 
 impl Hyperlink {
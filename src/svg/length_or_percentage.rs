@@ -1,8 +1,9 @@
 use std::fmt::{Debug, Display};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum LengthOrPercentage {
     Number(isize),
+    Decimal(f64),
     Em(isize),
     Ex(isize),
     Pixels(isize),
@@ -41,10 +42,87 @@ impl LengthOrPercentage {
     }
 }
 
+impl From<&str> for LengthOrPercentage {
+    fn from(raw: &str) -> Self {
+        Self::Raw(raw.to_owned())
+    }
+}
+
+impl From<String> for LengthOrPercentage {
+    fn from(raw: String) -> Self {
+        Self::Raw(raw)
+    }
+}
+
+macro_rules! from_whole_number {
+    ($($typ:ty),+) => {
+        $(
+            impl From<$typ> for LengthOrPercentage {
+                fn from(number: $typ) -> Self {
+                    Self::Number(number as isize)
+                }
+            }
+        )+
+    };
+}
+
+from_whole_number!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+
+impl From<f32> for LengthOrPercentage {
+    fn from(number: f32) -> Self {
+        Self::Decimal(number as f64)
+    }
+}
+
+impl From<f64> for LengthOrPercentage {
+    fn from(number: f64) -> Self {
+        Self::Decimal(number)
+    }
+}
+
+/// Argument of the SVG macros, converted with [From] when possible and into a
+/// [LengthOrPercentage::Raw] value through its [Display] implementation otherwise
+#[doc(hidden)]
+pub struct LengthArgument<T>(pub T);
+
+#[doc(hidden)]
+pub trait LengthFromConversion {
+    fn length_or_percentage(&self) -> LengthOrPercentage;
+}
+
+impl<T> LengthFromConversion for LengthArgument<T>
+where
+    T: Clone + Into<LengthOrPercentage>,
+{
+    fn length_or_percentage(&self) -> LengthOrPercentage {
+        self.0.clone().into()
+    }
+}
+
+#[doc(hidden)]
+pub trait LengthFromDisplay {
+    fn length_or_percentage(&self) -> LengthOrPercentage;
+}
+
+impl<T> LengthFromDisplay for &LengthArgument<T>
+where
+    T: Display,
+{
+    fn length_or_percentage(&self) -> LengthOrPercentage {
+        LengthOrPercentage::new(&self.0)
+    }
+}
+
 impl Display for LengthOrPercentage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             LengthOrPercentage::Number(num) => f.write_fmt(format_args!("{}", num)),
+            LengthOrPercentage::Decimal(num) => {
+                // at most three decimals, without trailing zeroes, keeps the SVG compact
+                let decimal = format!("{:.3}", num);
+                let decimal = decimal.trim_end_matches('0').trim_end_matches('.');
+                f.write_str(if decimal == "-0" { "0" } else { decimal })
+            }
             LengthOrPercentage::Em(em) => f.write_fmt(format_args!("{}em", em)),
             LengthOrPercentage::Ex(ex) => f.write_fmt(format_args!("{}ex", ex)),
             LengthOrPercentage::Pixels(px) => {
@@ -60,3 +138,47 @@ impl Display for LengthOrPercentage {
         }
     }
 }
+
+#[test]
+fn decimals() {
+    assert_eq!(LengthOrPercentage::from(12.5).to_string(), "12.5");
+    assert_eq!(LengthOrPercentage::from(0.1 + 0.2).to_string(), "0.3");
+    assert_eq!(LengthOrPercentage::from(2.0_f32).to_string(), "2");
+    assert_eq!(LengthOrPercentage::from(-0.0001).to_string(), "0");
+    assert_eq!(LengthOrPercentage::from(1.23456).to_string(), "1.235");
+    assert_eq!(LengthOrPercentage::from(42_usize).to_string(), "42");
+    assert_eq!(LengthOrPercentage::from("50%").to_string(), "50%");
+}
+
+#[test]
+fn macro_arguments() {
+    use crate::Document;
+
+    struct Inches(f32);
+
+    impl Display for Inches {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}in", self.0)
+        }
+    }
+
+    assert_eq!(
+        crate::length_or_percentage!(12.5),
+        LengthOrPercentage::Decimal(12.5)
+    );
+    assert_eq!(
+        crate::length_or_percentage!(7_u16),
+        LengthOrPercentage::Number(7)
+    );
+    assert_eq!(
+        crate::length_or_percentage!(String::from("50%")),
+        LengthOrPercentage::Raw("50%".to_owned())
+    );
+    assert_eq!(
+        crate::length_or_percentage!(Inches(1.5)),
+        LengthOrPercentage::Raw("1.5in".to_owned())
+    );
+
+    let svg = crate::svg!(100, 100, [crate::line!((0, 0), (Inches(2.0), "50%"))]).to_string();
+    assert!(svg.contains(r#"<line x1="0" y1="0" x2="2in" y2="50%"/>"#));
+}
@@ -3,7 +3,9 @@ use std::fmt::Display;
 use simple_xml_serialize::XMLElement;
 use simple_xml_serialize_macro::xml_element;
 
-use crate::LengthOrPercentage;
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
 
 #[xml_element("line")]
 #[derive(Default)]
@@ -28,10 +30,56 @@ pub struct Line {
 
     #[sxs_type_attr]
     y2: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_attr(rename = "marker-start")]
+    marker_start: Option<Url>,
+
+    #[sxs_type_attr(rename = "marker-mid")]
+    marker_mid: Option<Url>,
+
+    #[sxs_type_attr(rename = "marker-end")]
+    marker_end: Option<Url>,
 }
 
 global_attributes!(Line);
 
+presentation_attributes!(Line);
+
+marker_attributes!(Line);
+
 impl Line {
     pub fn new(
         x1: LengthOrPercentage,
@@ -1,5 +1,4 @@
-use crate::LengthOrPercentage;
-use std::fmt::Display;
+use crate::{LengthOrPercentage, Text};
 
 macro_rules! global_attributes {
     ($structure:ty) => {
@@ -36,6 +35,129 @@ macro_rules! global_attributes {
     };
 }
 
+macro_rules! presentation_attributes {
+    ($structure:ty) => {
+        impl $structure {
+            /// Paint the interior with a colour, or with a gradient or pattern via its [Url](crate::Url)
+            pub fn with_fill<P>(mut self, fill: P) -> Self
+            where
+                P: Into<$crate::Paint>,
+            {
+                self.fill = Some(fill.into());
+
+                self
+            }
+
+            /// Paint the outline with a colour, or with a gradient or pattern via its [Url](crate::Url)
+            pub fn with_stroke<P>(mut self, stroke: P) -> Self
+            where
+                P: Into<$crate::Paint>,
+            {
+                self.stroke = Some(stroke.into());
+
+                self
+            }
+
+            pub fn with_stroke_width<L>(mut self, width: L) -> Self
+            where
+                L: Into<$crate::LengthOrPercentage>,
+            {
+                self.stroke_width = Some(width.into());
+
+                self
+            }
+
+            /// Dash the outline with alternating dash and gap lengths
+            pub fn with_dasharray<I, L>(mut self, lengths: I) -> Self
+            where
+                I: IntoIterator<Item = L>,
+                L: Into<$crate::LengthOrPercentage>,
+            {
+                self.dasharray = Some($crate::DashArray::new(lengths));
+
+                self
+            }
+
+            /// Opacity of the whole element, clamped between 0 (transparent) and 1 (opaque)
+            pub fn with_opacity(mut self, opacity: f64) -> Self {
+                self.opacity = Some(opacity.clamp(0.0, 1.0));
+
+                self
+            }
+
+            pub fn with_font_size<L>(mut self, size: L) -> Self
+            where
+                L: Into<$crate::LengthOrPercentage>,
+            {
+                self.font_size = Some(size.into());
+
+                self
+            }
+
+            pub fn with_text_anchor(mut self, anchor: $crate::TextAnchor) -> Self {
+                self.text_anchor = Some(anchor);
+
+                self
+            }
+
+            pub fn with_transform(mut self, transform: $crate::Transform) -> Self {
+                self.transform = Some($crate::TransformAttribute(transform));
+
+                self
+            }
+
+            pub fn with_clip_path(mut self, clip_path: $crate::Url) -> Self {
+                self.clip_path = Some(clip_path);
+
+                self
+            }
+
+            pub fn with_mask(mut self, mask: $crate::Url) -> Self {
+                self.mask = Some(mask);
+
+                self
+            }
+
+            /// Inline declarations, which take precedence over the stylesheet and the attributes
+            pub fn with_style<I>(mut self, declarations: I) -> Self
+            where
+                I: IntoIterator<Item = $crate::Styling>,
+            {
+                self.style = Some($crate::InlineStyle::new(declarations));
+
+                self
+            }
+        }
+    };
+}
+
+macro_rules! marker_attributes {
+    ($structure:ty) => {
+        impl $structure {
+            /// Draw a [Marker](crate::Marker) at the first vertex
+            pub fn with_marker_start(mut self, marker: $crate::Url) -> Self {
+                self.marker_start = Some(marker);
+
+                self
+            }
+
+            /// Draw a [Marker](crate::Marker) at every vertex between the first and the last
+            pub fn with_marker_mid(mut self, marker: $crate::Url) -> Self {
+                self.marker_mid = Some(marker);
+
+                self
+            }
+
+            /// Draw a [Marker](crate::Marker) at the last vertex
+            pub fn with_marker_end(mut self, marker: $crate::Url) -> Self {
+                self.marker_end = Some(marker);
+
+                self
+            }
+        }
+    };
+}
+
 macro_rules! has_children {
     ($structure:ty) => {
         impl $structure {
@@ -128,6 +250,62 @@ macro_rules! add_global_attribute {
         $var = $var.with_meta($meta);
     };
 
+    ($var:ident fill: $fill:expr) => {
+        $var = $var.with_fill($fill);
+    };
+
+    ($var:ident stroke: $stroke:expr) => {
+        $var = $var.with_stroke($stroke);
+    };
+
+    ($var:ident stroke_width: $width:expr) => {
+        $var = $var.with_stroke_width($width);
+    };
+
+    ($var:ident dasharray: $lengths:expr) => {
+        $var = $var.with_dasharray($lengths);
+    };
+
+    ($var:ident opacity: $opacity:expr) => {
+        $var = $var.with_opacity($opacity);
+    };
+
+    ($var:ident font_size: $size:expr) => {
+        $var = $var.with_font_size($size);
+    };
+
+    ($var:ident text_anchor: $anchor:expr) => {
+        $var = $var.with_text_anchor($anchor);
+    };
+
+    ($var:ident transform: $transform:expr) => {
+        $var = $var.with_transform($transform);
+    };
+
+    ($var:ident clip_path: $clip_path:expr) => {
+        $var = $var.with_clip_path($clip_path);
+    };
+
+    ($var:ident mask: $mask:expr) => {
+        $var = $var.with_mask($mask);
+    };
+
+    ($var:ident style: $declarations:expr) => {
+        $var = $var.with_style($declarations);
+    };
+
+    ($var:ident marker_start: $marker:expr) => {
+        $var = $var.with_marker_start($marker);
+    };
+
+    ($var:ident marker_mid: $marker:expr) => {
+        $var = $var.with_marker_mid($marker);
+    };
+
+    ($var:ident marker_end: $marker:expr) => {
+        $var = $var.with_marker_end($marker);
+    };
+
     ($var:ident $name:ident: $($ignored:tt)* ) => {
         compile_error!("argument not recognised");
     };
@@ -154,18 +332,29 @@ macro_rules! add_global_attributes_args {
     ($var:ident <- $name:ident: $($ignored:tt)* ) => {};
 }
 
+/// Numbers become exact lengths, any other [Display](std::fmt::Display) value is written as is
+#[doc(hidden)]
+#[macro_export]
+macro_rules! length_or_percentage {
+    ($value:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{LengthFromConversion as _, LengthFromDisplay as _};
+        (&$crate::LengthArgument($value)).length_or_percentage()
+    }};
+}
+
 #[macro_export]
 macro_rules! line {
     ($from:expr, $to:expr $(, $($attr_name:ident: $attr_val:expr),+)?) => {{
-        #[allow(unused_mut)]
         let (x1,y1) = $from;
         let (x2,y2) = $to;
 
+        #[allow(unused_mut)]
         let mut line = $crate::Line::new(
-            LengthOrPercentage::new(x1),
-            LengthOrPercentage::new(y1),
-            LengthOrPercentage::new(x2),
-            LengthOrPercentage::new(y2)
+            $crate::length_or_percentage!(x1),
+            $crate::length_or_percentage!(y1),
+            $crate::length_or_percentage!(x2),
+            $crate::length_or_percentage!(y2)
         );
 
         $($(
@@ -179,13 +368,13 @@ macro_rules! line {
 #[macro_export]
 macro_rules! circle {
     ($center:expr, $radius:expr $(, $($attr_name:ident: $attr_val:expr),+)?) => {{
-        #[allow(unused_mut)]
         let (cx, cy) = $center;
 
+        #[allow(unused_mut)]
         let mut circle = $crate::Circle::new(
-            LengthOrPercentage::new(cx),
-            LengthOrPercentage::new(cy),
-            LengthOrPercentage::new($radius)
+            $crate::length_or_percentage!(cx),
+            $crate::length_or_percentage!(cy),
+            $crate::length_or_percentage!($radius)
         );
 
         $($(
@@ -196,11 +385,109 @@ macro_rules! circle {
     }}
 }
 
+#[macro_export]
+macro_rules! rect {
+    ($at:expr, $size:expr, radius: $radius:expr $(, $($attr_name:ident: $attr_val:expr),+)?) => {{
+        let (rx, ry) = $radius;
+
+        $crate::rect!( $at, $size $(, $($attr_name: $attr_val),+)? ).radius(
+            $crate::length_or_percentage!(rx),
+            $crate::length_or_percentage!(ry)
+        )
+    }};
+
+    ($at:expr, $size:expr $(, $($attr_name:ident: $attr_val:expr),+)?) => {{
+        let (x, y) = $at;
+        let (width, height) = $size;
+
+        #[allow(unused_mut)]
+        let mut rect = $crate::Rect::new(
+            $crate::length_or_percentage!(x),
+            $crate::length_or_percentage!(y),
+            $crate::length_or_percentage!(width),
+            $crate::length_or_percentage!(height)
+        );
+
+        $($(
+            $crate::add_global_attribute!( rect $attr_name: $attr_val );
+        )+)?
+
+        rect
+    }}
+}
+
+#[macro_export]
+macro_rules! ellipse {
+    ($center:expr, $radius:expr $(, $($attr_name:ident: $attr_val:expr),+)?) => {{
+        let (cx, cy) = $center;
+        let (rx, ry) = $radius;
+
+        #[allow(unused_mut)]
+        let mut ellipse = $crate::Ellipse::new(
+            $crate::length_or_percentage!(cx),
+            $crate::length_or_percentage!(cy),
+            $crate::length_or_percentage!(rx),
+            $crate::length_or_percentage!(ry)
+        );
+
+        $($(
+            $crate::add_global_attribute!( ellipse $attr_name: $attr_val );
+        )+)?
+
+        ellipse
+    }}
+}
+
+#[macro_export]
+macro_rules! polyline {
+    ($points:expr $(, $($attr_name:ident: $attr_val:expr),+)?) => {{
+        #[allow(unused_mut)]
+        let mut polyline = $crate::Polyline::new(
+            ($points).into_iter().map(|(x, y)| (x as f64, y as f64)).collect()
+        );
+
+        $($(
+            $crate::add_global_attribute!( polyline $attr_name: $attr_val );
+        )+)?
+
+        polyline
+    }}
+}
+
+#[macro_export]
+macro_rules! polygon {
+    ($points:expr $(, $($attr_name:ident: $attr_val:expr),+)?) => {{
+        #[allow(unused_mut)]
+        let mut polygon = $crate::Polygon::new(
+            ($points).into_iter().map(|(x, y)| (x as f64, y as f64)).collect()
+        );
+
+        $($(
+            $crate::add_global_attribute!( polygon $attr_name: $attr_val );
+        )+)?
+
+        polygon
+    }}
+}
+
+#[macro_export]
+macro_rules! path {
+    ($data:expr $(, $($attr_name:ident: $attr_val:expr),+)?) => {{
+        #[allow(unused_mut)]
+        let mut path = $crate::Path::new( $data );
+
+        $($(
+            $crate::add_global_attribute!( path $attr_name: $attr_val );
+        )+)?
+
+        path
+    }}
+}
+
 #[derive(Default)]
 pub struct TextArgs {
-    pub id: Option<String>,
-    pub class: Option<String>,
-    pub meta: Option<String>,
+    /// Collects the global and presentation attributes
+    pub element: Text,
 
     pub text: Option<String>,
     pub at: Option<(LengthOrPercentage, LengthOrPercentage)>,
@@ -208,8 +495,6 @@ pub struct TextArgs {
     pub rotate: Option<u16>,
 }
 
-global_attributes!(TextArgs);
-
 #[macro_export]
 macro_rules! text_args {
     (@munch $var:ident ) => {};
@@ -225,12 +510,12 @@ macro_rules! text_args {
 
     ($var:ident at: $coord:expr ) => {
         let coord = $coord;
-        $var.at = Some(( LengthOrPercentage::new(coord.0), LengthOrPercentage::new(coord.1) ));
+        $var.at = Some(( $crate::length_or_percentage!(coord.0), $crate::length_or_percentage!(coord.1) ));
     };
 
     ($var:ident relative: $coord:expr ) => {
         let coord = $coord;
-        $var.relative = Some(( LengthOrPercentage::new(coord.0), LengthOrPercentage::new(coord.1) ));
+        $var.relative = Some(( $crate::length_or_percentage!(coord.0), $crate::length_or_percentage!(coord.1) ));
     };
 
     ($var:ident rotate: $angle:expr ) => {
@@ -238,7 +523,9 @@ macro_rules! text_args {
     };
 
     ($var:ident $name:ident: $val:expr ) => {
-        $crate::add_global_attribute!( $var $name: $val );
+        let mut element = std::mem::take( &mut $var.element );
+        $crate::add_global_attribute!( element $name: $val );
+        $var.element = element;
     };
 
 }
@@ -250,7 +537,7 @@ macro_rules! text {
         let mut args = $crate::TextArgs::default();
         $crate::text_args!( @munch args $($all)* );
 
-        let mut txt = $crate::Text::new( args.text.expect("Text is not optional") );
+        let mut txt = args.element.text( args.text.expect("Text is not optional") );
 
         if let Some(at) = args.at {
             txt = txt.at( at.0, at.1 );
@@ -258,14 +545,6 @@ macro_rules! text {
             txt = txt.relative( relative.0, relative.1 );
         }
 
-        if let Some(id) = args.id {
-            txt = txt.with_id( id );
-        }
-
-        if let Some(class) = args.class {
-            txt = txt.with_class( class);
-        }
-
         txt
     }}
 }
@@ -350,9 +629,9 @@ macro_rules! ticks {
 
             if let Some(text) = $crate::expr_or!( None => $(($tick_label)(domain, dimension))? ) {
                 let (text_x, text_y) = if $is_vertical {
-                    (((base_start as isize) + label_x_offset) as usize, ((dimension as isize) + label_y_offset) as usize )
+                    ((base_start as f64) + (label_x_offset as f64), (dimension as f64) + (label_y_offset as f64))
                 } else {
-                    (((dimension as isize) + label_x_offset) as usize, ((base_end as isize) + label_y_offset) as usize )
+                    ((dimension as f64) + (label_x_offset as f64), (base_end as f64) + (label_y_offset as f64))
                 };
                 grp.add(d3rs::text!(text: text, at: (text_x, text_y), class: "tick-label" ));
             }
@@ -0,0 +1,256 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    Angle, DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+/// Arrowhead or other glyph drawn at the vertices of a line, path, polyline or polygon
+///
+/// # Example
+/// ```rust
+/// # use d3rs::{Document, Group, Marker, Orient, PathData};
+///
+/// let mut arrow = Marker::new("arrow")
+///     .view_box(0.0, 0.0, 10.0, 10.0)
+///     .reference(5.0, 5.0)
+///     .orient(Orient::AutoStartReverse);
+/// arrow.add(d3rs::path!(PathData::new().move_to(0.0, 0.0).line_to(10.0, 5.0).line_to(0.0, 10.0).close()));
+///
+/// let annotation = d3rs::line!((10, 10), (90, 10), marker_end: arrow.url());
+/// let svg = d3rs::svg!(100, 20, [arrow, annotation]).to_string();
+///
+/// assert!(svg.contains(r#"marker-end="url(#arrow)""#));
+/// ```
+#[xml_element("marker")]
+#[derive(Default)]
+pub struct Marker {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr(rename = "viewBox")]
+    view_box: Option<String>,
+
+    #[sxs_type_attr(rename = "refX")]
+    ref_x: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "refY")]
+    ref_y: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "markerWidth")]
+    width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "markerHeight")]
+    height: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "markerUnits")]
+    units: Option<MarkerUnits>,
+
+    #[sxs_type_attr]
+    orient: Option<Orient>,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_multi_element]
+    items: Vec<XMLElement>,
+}
+
+/// The coordinate system of the size of a [Marker]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerUnits {
+    /// Multiples of the stroke width of the element that references it
+    StrokeWidth,
+    UserSpaceOnUse,
+}
+
+/// The rotation of a [Marker]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Orient {
+    /// Along the direction of the path at the vertex
+    Auto,
+    /// Along the direction of the path, but reversed at the start, e.g. for double-headed arrows
+    AutoStartReverse,
+    Angle(Angle),
+}
+
+global_attributes!(Marker);
+
+presentation_attributes!(Marker);
+
+has_children!(Marker);
+
+impl Marker {
+    pub fn new<D>(id: D) -> Self
+    where
+        D: Display,
+    {
+        Self::default().with_id(id)
+    }
+
+    /// Reference to the marker, for a `marker-start`, `marker-mid` or `marker-end` attribute
+    pub fn url(&self) -> Url {
+        Url::new(self.id.as_deref().unwrap_or_default())
+    }
+
+    /// The coordinates of the contents that are mapped onto the size of the marker
+    pub fn view_box(mut self, min_x: f64, min_y: f64, width: f64, height: f64) -> Self {
+        self.view_box = Some(view_box(min_x, min_y, width, height));
+
+        self
+    }
+
+    /// The point of the contents that is placed on the vertex
+    pub fn reference(mut self, x: f64, y: f64) -> Self {
+        self.ref_x = Some(LengthOrPercentage::from(x));
+        self.ref_y = Some(LengthOrPercentage::from(y));
+
+        self
+    }
+
+    pub fn size(mut self, width: LengthOrPercentage, height: LengthOrPercentage) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+
+        self
+    }
+
+    pub fn units(mut self, units: MarkerUnits) -> Self {
+        self.units = Some(units);
+
+        self
+    }
+
+    pub fn orient(mut self, orient: Orient) -> Self {
+        self.orient = Some(orient);
+
+        self
+    }
+}
+
+/// The value of a `viewBox` attribute
+pub(crate) fn view_box(min_x: f64, min_y: f64, width: f64, height: f64) -> String {
+    format!(
+        "{} {} {} {}",
+        LengthOrPercentage::from(min_x),
+        LengthOrPercentage::from(min_y),
+        LengthOrPercentage::from(width),
+        LengthOrPercentage::from(height)
+    )
+}
+
+impl Display for MarkerUnits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MarkerUnits::StrokeWidth => "strokeWidth",
+            MarkerUnits::UserSpaceOnUse => "userSpaceOnUse",
+        })
+    }
+}
+
+impl Display for Orient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Orient::Auto => f.write_str("auto"),
+            Orient::AutoStartReverse => f.write_str("auto-start-reverse"),
+            // a plain number of degrees is understood by SVG 1.1 renderers as well
+            Orient::Angle(angle) => LengthOrPercentage::from(angle.to_degrees()).fmt(f),
+        }
+    }
+}
+
+#[test]
+fn markers_on_vertices() {
+    use crate::{Document, PathData};
+
+    let mut dot = Marker::new("dot")
+        .view_box(-1.0, -1.0, 2.0, 2.0)
+        .size(4.into(), 4.into())
+        .units(MarkerUnits::UserSpaceOnUse)
+        .orient(Orient::Angle(Angle::Turns(0.25)));
+    dot.add(crate::circle!((0, 0), 1));
+
+    let zigzag = PathData::new()
+        .move_to(0.0, 0.0)
+        .line_to(10.0, 10.0)
+        .line_to(20.0, 0.0);
+    let svg = crate::svg!(
+        20,
+        10,
+        [
+            dot,
+            crate::path!(zigzag, marker_start: Url::new("dot"), marker_mid: Url::new("dot")),
+            crate::polyline!(vec![(0, 5), (20, 5)], marker_end: Url::new("dot"))
+        ]
+    )
+    .to_string();
+
+    assert!(svg.contains(
+        r#"<marker id="dot" viewBox="-1 -1 2 2" markerWidth="4" markerHeight="4" markerUnits="userSpaceOnUse" orient="90">"#
+    ));
+    assert!(svg.contains(
+        r#"<path d="M0 0 10 10 20 0" marker-start="url(#dot)" marker-mid="url(#dot)"/>"#
+    ));
+    assert!(svg.contains(r#"<polyline points="0,5 20,5" marker-end="url(#dot)"/>"#));
+}
+
+#[test]
+fn styled_containers() {
+    use crate::{ColorName, Document, Styling, Transform, TransformFunction};
+
+    let arrow = Marker::new("arrow")
+        .with_fill(ColorName::Red)
+        .with_transform(Transform::new(TransformFunction::Rotate(Angle::Degrees(
+            90.0,
+        ))));
+
+    let mut svg = Document::new(10, 10)
+        .with_css("circle { fill: blue; }")
+        .with_style([Styling::Raw("background: white".to_owned())])
+        .with_opacity(0.5);
+    svg.add(arrow);
+    let svg = svg.to_string();
+
+    assert!(svg.contains(r#"<marker id="arrow" fill="red" transform="rotate(90)"/>"#));
+    assert!(svg.contains(r#"opacity="0.5" style="background: white">"#));
+    // the stylesheet is still a child element
+    assert!(svg.contains("circle { fill: blue; }"));
+}
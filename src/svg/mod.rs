@@ -8,6 +8,12 @@ pub use document::*;
 mod styles;
 pub use styles::*;
 
+mod color;
+pub use color::*;
+
+mod color_name;
+pub use color_name::*;
+
 mod group;
 pub use group::*;
 
@@ -20,8 +26,50 @@ pub use line::*;
 mod circle;
 pub use circle::*;
 
+mod path;
+pub use path::*;
+
+mod rect;
+pub use rect::*;
+
+mod ellipse;
+pub use ellipse::*;
+
+mod polyline;
+pub use polyline::*;
+
+mod polygon;
+pub use polygon::*;
+
 mod text;
 pub use text::*;
 
 mod length_or_percentage;
 pub use length_or_percentage::*;
+
+mod paint;
+pub use paint::*;
+
+mod defs;
+pub use defs::*;
+
+mod gradient;
+pub use gradient::*;
+
+mod pattern;
+pub use pattern::*;
+
+mod clip_path;
+pub use clip_path::*;
+
+mod mask;
+pub use mask::*;
+
+mod marker;
+pub use marker::*;
+
+mod symbol;
+pub use symbol::*;
+
+mod use_element;
+pub use use_element::*;
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use crate::{Color, ColorName};
+
+/// Reference to an element with an id, like a gradient or a clip path, as `url(#id)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Url {
+    id: String,
+}
+
+/// How the fill or the stroke of a shape is painted
+#[derive(Debug, Clone, PartialEq)]
+pub enum Paint {
+    None,
+    CurrentColor,
+    Color(Color),
+    /// A gradient or a pattern
+    Url(Url),
+}
+
+impl Url {
+    pub fn new<D>(id: D) -> Self
+    where
+        D: Display,
+    {
+        Self {
+            id: format!("{}", id),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Display for Url {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("url(#{})", self.id))
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(color: Color) -> Self {
+        Self::Color(color)
+    }
+}
+
+impl From<ColorName> for Paint {
+    fn from(name: ColorName) -> Self {
+        Self::Color(Color::Name(name))
+    }
+}
+
+impl From<Url> for Paint {
+    fn from(url: Url) -> Self {
+        Self::Url(url)
+    }
+}
+
+impl Display for Paint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Paint::None => f.write_str("none"),
+            Paint::CurrentColor => f.write_str("currentColor"),
+            Paint::Color(color) => color.fmt(f),
+            Paint::Url(url) => url.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,505 @@
+use std::fmt::{Display, Write};
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+#[xml_element("path")]
+#[derive(Default)]
+pub struct Path {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    d: PathData,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_attr(rename = "marker-start")]
+    marker_start: Option<Url>,
+
+    #[sxs_type_attr(rename = "marker-mid")]
+    marker_mid: Option<Url>,
+
+    #[sxs_type_attr(rename = "marker-end")]
+    marker_end: Option<Url>,
+}
+
+global_attributes!(Path);
+
+presentation_attributes!(Path);
+
+marker_attributes!(Path);
+
+impl Path {
+    pub fn new(d: PathData) -> Self {
+        Self {
+            d,
+            ..Self::default()
+        }
+    }
+}
+
+/// A single command of [PathData]; the `To` commands take absolute coordinates,
+/// the `By` commands take coordinates relative to the current point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+    MoveTo(f64, f64),
+    MoveBy(f64, f64),
+    LineTo(f64, f64),
+    LineBy(f64, f64),
+    HorizontalTo(f64),
+    HorizontalBy(f64),
+    VerticalTo(f64),
+    VerticalBy(f64),
+    CubicTo {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        x: f64,
+        y: f64,
+    },
+    CubicBy {
+        dx1: f64,
+        dy1: f64,
+        dx2: f64,
+        dy2: f64,
+        dx: f64,
+        dy: f64,
+    },
+    QuadraticTo {
+        x1: f64,
+        y1: f64,
+        x: f64,
+        y: f64,
+    },
+    QuadraticBy {
+        dx1: f64,
+        dy1: f64,
+        dx: f64,
+        dy: f64,
+    },
+    ArcTo {
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    },
+    ArcBy {
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        dx: f64,
+        dy: f64,
+    },
+    Close,
+}
+
+impl PathCommand {
+    /// The letter of the command in the `d` attribute
+    pub fn letter(&self) -> char {
+        match self {
+            PathCommand::MoveTo(..) => 'M',
+            PathCommand::MoveBy(..) => 'm',
+            PathCommand::LineTo(..) => 'L',
+            PathCommand::LineBy(..) => 'l',
+            PathCommand::HorizontalTo(..) => 'H',
+            PathCommand::HorizontalBy(..) => 'h',
+            PathCommand::VerticalTo(..) => 'V',
+            PathCommand::VerticalBy(..) => 'v',
+            PathCommand::CubicTo { .. } => 'C',
+            PathCommand::CubicBy { .. } => 'c',
+            PathCommand::QuadraticTo { .. } => 'Q',
+            PathCommand::QuadraticBy { .. } => 'q',
+            PathCommand::ArcTo { .. } => 'A',
+            PathCommand::ArcBy { .. } => 'a',
+            PathCommand::Close => 'Z',
+        }
+    }
+
+    /// The arguments of the command in the order of the `d` attribute, with the arc flags as 0 or 1
+    pub fn arguments(&self) -> Vec<f64> {
+        let flag = |flag: bool| if flag { 1.0 } else { 0.0 };
+
+        match *self {
+            PathCommand::MoveTo(x, y)
+            | PathCommand::MoveBy(x, y)
+            | PathCommand::LineTo(x, y)
+            | PathCommand::LineBy(x, y) => vec![x, y],
+            PathCommand::HorizontalTo(value)
+            | PathCommand::HorizontalBy(value)
+            | PathCommand::VerticalTo(value)
+            | PathCommand::VerticalBy(value) => vec![value],
+            PathCommand::CubicTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => vec![x1, y1, x2, y2, x, y],
+            PathCommand::CubicBy {
+                dx1,
+                dy1,
+                dx2,
+                dy2,
+                dx,
+                dy,
+            } => vec![dx1, dy1, dx2, dy2, dx, dy],
+            PathCommand::QuadraticTo { x1, y1, x, y } => vec![x1, y1, x, y],
+            PathCommand::QuadraticBy { dx1, dy1, dx, dy } => vec![dx1, dy1, dx, dy],
+            PathCommand::ArcTo {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+                x,
+                y,
+            } => vec![rx, ry, rotation, flag(large_arc), flag(sweep), x, y],
+            PathCommand::ArcBy {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+                dx,
+                dy,
+            } => vec![rx, ry, rotation, flag(large_arc), flag(sweep), dx, dy],
+            PathCommand::Close => vec![],
+        }
+    }
+
+    /// The command that a repetition of this command's arguments implies when its letter is left out
+    fn repeated(&self) -> char {
+        match self.letter() {
+            'M' => 'L',
+            'm' => 'l',
+            letter => letter,
+        }
+    }
+}
+
+/// The outline of a [Path], built from move, line, curve and arc commands
+///
+/// It serialises to a compact `d` attribute: repeated letters, unneeded separators
+/// and leading zeroes are left out, and numbers are rounded to three decimals.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::PathData;
+///
+/// let triangle = PathData::new()
+///     .move_to(10.0, 10.0)
+///     .line_to(20.0, 10.0)
+///     .line_by(-5.0, -8.5)
+///     .close();
+///
+/// assert_eq!(triangle.to_string(), "M10 10 20 10l-5-8.5Z");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PathData {
+    commands: Vec<PathCommand>,
+}
+
+impl PathData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Append any command
+    pub fn command(mut self, command: PathCommand) -> Self {
+        self.commands.push(command);
+
+        self
+    }
+
+    /// Start a new subpath at the point
+    pub fn move_to(self, x: f64, y: f64) -> Self {
+        self.command(PathCommand::MoveTo(x, y))
+    }
+
+    pub fn move_by(self, dx: f64, dy: f64) -> Self {
+        self.command(PathCommand::MoveBy(dx, dy))
+    }
+
+    pub fn line_to(self, x: f64, y: f64) -> Self {
+        self.command(PathCommand::LineTo(x, y))
+    }
+
+    pub fn line_by(self, dx: f64, dy: f64) -> Self {
+        self.command(PathCommand::LineBy(dx, dy))
+    }
+
+    pub fn horizontal_to(self, x: f64) -> Self {
+        self.command(PathCommand::HorizontalTo(x))
+    }
+
+    pub fn horizontal_by(self, dx: f64) -> Self {
+        self.command(PathCommand::HorizontalBy(dx))
+    }
+
+    pub fn vertical_to(self, y: f64) -> Self {
+        self.command(PathCommand::VerticalTo(y))
+    }
+
+    pub fn vertical_by(self, dy: f64) -> Self {
+        self.command(PathCommand::VerticalBy(dy))
+    }
+
+    /// Cubic Bézier curve to `(x, y)`, with control points `(x1, y1)` and `(x2, y2)`
+    pub fn cubic_to(self, x1: f64, y1: f64, x2: f64, y2: f64, x: f64, y: f64) -> Self {
+        self.command(PathCommand::CubicTo {
+            x1,
+            y1,
+            x2,
+            y2,
+            x,
+            y,
+        })
+    }
+
+    pub fn cubic_by(self, dx1: f64, dy1: f64, dx2: f64, dy2: f64, dx: f64, dy: f64) -> Self {
+        self.command(PathCommand::CubicBy {
+            dx1,
+            dy1,
+            dx2,
+            dy2,
+            dx,
+            dy,
+        })
+    }
+
+    /// Quadratic Bézier curve to `(x, y)`, with control point `(x1, y1)`
+    pub fn quadratic_to(self, x1: f64, y1: f64, x: f64, y: f64) -> Self {
+        self.command(PathCommand::QuadraticTo { x1, y1, x, y })
+    }
+
+    pub fn quadratic_by(self, dx1: f64, dy1: f64, dx: f64, dy: f64) -> Self {
+        self.command(PathCommand::QuadraticBy { dx1, dy1, dx, dy })
+    }
+
+    /// Elliptical arc to `(x, y)`, with radii `rx` and `ry` and the x-axis rotated by `rotation` degrees
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_to(
+        self,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    ) -> Self {
+        self.command(PathCommand::ArcTo {
+            rx,
+            ry,
+            rotation,
+            large_arc,
+            sweep,
+            x,
+            y,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_by(
+        self,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        dx: f64,
+        dy: f64,
+    ) -> Self {
+        self.command(PathCommand::ArcBy {
+            rx,
+            ry,
+            rotation,
+            large_arc,
+            sweep,
+            dx,
+            dy,
+        })
+    }
+
+    /// Close the current subpath with a straight line to its start
+    pub fn close(self) -> Self {
+        self.command(PathCommand::Close)
+    }
+}
+
+/// A polyline through the points
+impl FromIterator<(f64, f64)> for PathData {
+    fn from_iter<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        points.into_iter().fold(PathData::new(), |path, (x, y)| {
+            if path.is_empty() {
+                path.move_to(x, y)
+            } else {
+                path.line_to(x, y)
+            }
+        })
+    }
+}
+
+/// A number with at most three decimals and without a leading zero
+fn compact_number(number: f64) -> String {
+    let number = LengthOrPercentage::Decimal(number).to_string();
+
+    if let Some(fraction) = number.strip_prefix("0.") {
+        format!(".{}", fraction)
+    } else if let Some(fraction) = number.strip_prefix("-0.") {
+        format!("-.{}", fraction)
+    } else {
+        number
+    }
+}
+
+impl Display for PathData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut previous: Option<&PathCommand> = None;
+        let mut last_number: Option<String> = None;
+
+        for command in &self.commands {
+            let arguments = command.arguments();
+            if arguments.is_empty() || previous.map(PathCommand::repeated) != Some(command.letter())
+            {
+                f.write_char(command.letter())?;
+                last_number = None;
+            }
+
+            for argument in arguments {
+                let number = compact_number(argument);
+                // a sign, or a second decimal point, already separates two numbers
+                let separated = number.starts_with('-')
+                    || number.starts_with('.')
+                        && last_number.as_ref().is_some_and(|last| last.contains('.'));
+                if last_number.is_some() && !separated {
+                    f.write_char(' ')?;
+                }
+                f.write_str(&number)?;
+                last_number = Some(number);
+            }
+
+            previous = Some(command);
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn serialise_path_data() {
+    let absolute = PathData::new()
+        .move_to(10.0, 20.0)
+        .horizontal_to(90.0)
+        .vertical_to(80.0)
+        .cubic_to(90.0, 100.0, 10.0, 100.0, 10.0, 80.0)
+        .quadratic_to(0.0, 50.0, 10.0, 20.0)
+        .arc_to(5.0, 5.0, 0.0, false, true, 20.0, 30.0)
+        .close();
+    assert_eq!(
+        absolute.to_string(),
+        "M10 20H90V80C90 100 10 100 10 80Q0 50 10 20A5 5 0 0 1 20 30Z"
+    );
+
+    let relative = PathData::new()
+        .move_by(0.5, -0.25)
+        .line_by(0.125, 0.5)
+        .line_by(-1.0, 0.0)
+        .horizontal_by(-0.3333)
+        .vertical_by(2.0)
+        .cubic_by(1.0, 1.0, 2.0, 2.0, 3.0, 0.0)
+        .quadratic_by(1.0, -1.0, 2.0, 0.0)
+        .arc_by(1.5, 1.0, 45.0, true, false, -3.0, 0.0)
+        .close()
+        .move_to(0.0, 0.0)
+        .move_to(1.0, 1.0);
+    assert_eq!(
+        relative.to_string(),
+        "m.5-.25.125.5-1 0h-.333v2c1 1 2 2 3 0q1-1 2 0a1.5 1 45 1 0-3 0ZM0 0M1 1"
+    );
+
+    let points: PathData = vec![(0.0, 0.0), (1.0, 2.0), (2.0, 4.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        points.commands(),
+        &[
+            PathCommand::MoveTo(0.0, 0.0),
+            PathCommand::LineTo(1.0, 2.0),
+            PathCommand::LineTo(2.0, 4.0)
+        ]
+    );
+    assert_eq!(PathData::new().to_string(), "");
+}
+
+#[test]
+fn render_path() {
+    use crate::Document;
+
+    let wave = PathData::new()
+        .move_to(0.0, 50.0)
+        .quadratic_to(25.0, 0.0, 50.0, 50.0);
+    let svg = crate::svg!(100, 100, [crate::path!(wave, class: "wave", id: "first")]).to_string();
+
+    assert!(svg.contains(r#"<path id="first" class="wave" d="M0 50Q25 0 50 50"/>"#));
+}
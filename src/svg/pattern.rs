@@ -0,0 +1,114 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Units, Url,
+};
+
+/// Tile of elements that is repeated to fill or stroke a shape, e.g. hatching
+#[xml_element("pattern")]
+#[derive(Default)]
+pub struct Pattern {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    x: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    y: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    width: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    height: LengthOrPercentage,
+
+    #[sxs_type_attr(rename = "patternUnits")]
+    units: Option<Units>,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_multi_element]
+    items: Vec<XMLElement>,
+}
+
+global_attributes!(Pattern);
+
+presentation_attributes!(Pattern);
+
+has_children!(Pattern);
+
+impl Pattern {
+    /// A tile of `width` by `height`, relative to the bounding box of the shape
+    /// unless [Units::UserSpaceOnUse] is set
+    pub fn new<D>(id: D, width: LengthOrPercentage, height: LengthOrPercentage) -> Self
+    where
+        D: Display,
+    {
+        Self {
+            width,
+            height,
+            ..Self::default()
+        }
+        .with_id(id)
+    }
+
+    /// Reference to the pattern, for a fill or a stroke
+    pub fn url(&self) -> Url {
+        Url::new(self.id.as_deref().unwrap_or_default())
+    }
+
+    /// Offset of the first tile
+    pub fn at(mut self, x: LengthOrPercentage, y: LengthOrPercentage) -> Self {
+        self.x = Some(x);
+        self.y = Some(y);
+
+        self
+    }
+
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = Some(units);
+
+        self
+    }
+}
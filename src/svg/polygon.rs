@@ -0,0 +1,84 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+use super::Points;
+
+/// Closed shape through a list of points; the last point connects to the first
+#[xml_element("polygon")]
+#[derive(Default)]
+pub struct Polygon {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    pub points: Points,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_attr(rename = "marker-start")]
+    marker_start: Option<Url>,
+
+    #[sxs_type_attr(rename = "marker-mid")]
+    marker_mid: Option<Url>,
+
+    #[sxs_type_attr(rename = "marker-end")]
+    marker_end: Option<Url>,
+}
+
+global_attributes!(Polygon);
+
+presentation_attributes!(Polygon);
+
+marker_attributes!(Polygon);
+
+impl Polygon {
+    pub fn new(points: Points) -> Self {
+        Self {
+            points,
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+/// The `points` of a [Polyline] or [Polygon](super::Polygon), as `x,y` pairs in user units
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Points {
+    points: Vec<(f64, f64)>,
+}
+
+impl Points {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    pub fn add(mut self, x: f64, y: f64) -> Self {
+        self.points.push((x, y));
+
+        self
+    }
+}
+
+impl FromIterator<(f64, f64)> for Points {
+    fn from_iter<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        Self {
+            points: points.into_iter().collect(),
+        }
+    }
+}
+
+impl Display for Points {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, (x, y)) in self.points.iter().enumerate() {
+            write!(
+                f,
+                "{}{},{}",
+                if index > 0 { " " } else { "" },
+                LengthOrPercentage::Decimal(*x),
+                LengthOrPercentage::Decimal(*y)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Open line through a list of points
+#[xml_element("polyline")]
+#[derive(Default)]
+pub struct Polyline {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    pub points: Points,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_attr(rename = "marker-start")]
+    marker_start: Option<Url>,
+
+    #[sxs_type_attr(rename = "marker-mid")]
+    marker_mid: Option<Url>,
+
+    #[sxs_type_attr(rename = "marker-end")]
+    marker_end: Option<Url>,
+}
+
+global_attributes!(Polyline);
+
+presentation_attributes!(Polyline);
+
+marker_attributes!(Polyline);
+
+impl Polyline {
+    pub fn new(points: Points) -> Self {
+        Self {
+            points,
+            ..Self::default()
+        }
+    }
+}
+
+#[test]
+fn serialise_points() {
+    let points = Points::new()
+        .add(0.0, 0.0)
+        .add(12.5, -3.0)
+        .add(0.1 + 0.2, 1e-4);
+    assert_eq!(points.to_string(), "0,0 12.5,-3 0.3,0");
+
+    let points: Points = vec![(1.0, 2.0), (3.0, 4.0)].into_iter().collect();
+    assert_eq!(points.points(), &[(1.0, 2.0), (3.0, 4.0)]);
+    assert_eq!(Points::new().to_string(), "");
+}
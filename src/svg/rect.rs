@@ -0,0 +1,129 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+#[xml_element("rect")]
+#[derive(Default)]
+pub struct Rect {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    pub x: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    pub y: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    pub width: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    pub height: LengthOrPercentage,
+
+    #[sxs_type_attr]
+    pub rx: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    pub ry: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+}
+
+global_attributes!(Rect);
+
+presentation_attributes!(Rect);
+
+impl Rect {
+    pub fn new(
+        x: LengthOrPercentage,
+        y: LengthOrPercentage,
+        width: LengthOrPercentage,
+        height: LengthOrPercentage,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            ..Self::default()
+        }
+    }
+
+    /// Round the corners with the horizontal radius `rx` and the vertical radius `ry`
+    pub fn radius(mut self, rx: LengthOrPercentage, ry: LengthOrPercentage) -> Self {
+        self.rx = Some(rx);
+        self.ry = Some(ry);
+
+        self
+    }
+}
+
+#[test]
+fn render_shapes() {
+    use crate::{Document, Group};
+
+    let svg = crate::svg!(
+        100,
+        100,
+        [
+            crate::rect!((10, 20), (30, 40.5), class: "bar"),
+            crate::rect!((0, 0), ("100%", "100%"), radius: (4, 2), id: "background"),
+            crate::ellipse!((50, 50), (20, 10)),
+            crate::group!(class: "outlines", [
+                crate::polyline!(vec![(0, 0), (10, 5), (20, 0)]),
+                crate::polygon!(vec![(0.5, 0.0), (1.0, 1.0), (0.0, 1.0)], class: "triangle")
+            ])
+        ]
+    )
+    .to_string();
+
+    assert!(svg.contains(r#"<rect class="bar" x="10" y="20" width="30" height="40.5"/>"#));
+    assert!(svg.contains(
+        r#"<rect id="background" x="0" y="0" width="100%" height="100%" rx="4" ry="2"/>"#
+    ));
+    assert!(svg.contains(r#"<ellipse cx="50" cy="50" rx="20" ry="10"/>"#));
+    assert!(svg.contains(r#"<polyline points="0,0 10,5 20,0"/>"#));
+    assert!(svg.contains(r#"<polygon class="triangle" points="0.5,0 1,1 0,1"/>"#));
+}
@@ -1,9 +1,11 @@
 use simple_xml_serialize::XMLElement;
 use simple_xml_serialize_macro::xml_element;
 
-use std::{collections::HashMap, fmt::Display};
+use std::fmt::Display;
 
-use crate::LengthOrPercentage;
+use indexmap::IndexMap;
+
+use crate::{ColorName, LengthOrPercentage};
 
 #[derive(Debug, Default)]
 #[xml_element("style")]
@@ -16,15 +18,15 @@ pub struct Styles {
 
 #[derive(Debug, Default)]
 pub struct CSSRules {
-    rules: HashMap<String, Vec<Styling>>,
+    rules: IndexMap<String, Vec<Styling>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Transform {
     functions: Vec<TransformFunction>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TransformFunction {
     Translate {
         x: LengthOrPercentage,
@@ -33,38 +35,27 @@ pub enum TransformFunction {
     Rotate(Angle),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Angle {
     Degrees(f32),
     Radians(f32),
     Turns(f32),
 }
 
-#[derive(Debug)]
-pub enum ColorName {
-    AliceBlue,
-    Red,
-    Green,
-    Blue,
-    Magenta,
-    White,
-    Black,
-}
-
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Color {
     Name(ColorName),
     Rgb(Rgb),
     Hex(u32),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ByteOrPercentage {
     Byte(u8),
     Percentage(f32),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Rgb {
     red: ByteOrPercentage,
     green: ByteOrPercentage,
@@ -80,6 +71,30 @@ pub enum Styling {
     Raw(String),
 }
 
+/// The declarations of a `style` attribute
+#[derive(Debug, Default)]
+pub struct InlineStyle {
+    declarations: Vec<Styling>,
+}
+
+/// Lengths of the alternating dashes and gaps of a stroke
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DashArray {
+    lengths: Vec<LengthOrPercentage>,
+}
+
+/// Alignment of a text relative to its position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// A [Transform] in the syntax of the `transform` attribute, rather than of CSS
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TransformAttribute(pub(crate) Transform);
+
 impl Styles {
     pub fn new() -> Self {
         Self::default()
@@ -98,22 +113,6 @@ impl Styles {
     }
 }
 
-impl Display for ColorName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let name = match self {
-            ColorName::AliceBlue => "aliceblue",
-            ColorName::Red => "red",
-            ColorName::Green => "green",
-            ColorName::Blue => "blue",
-            ColorName::Magenta => "magenta",
-            ColorName::White => "white",
-            ColorName::Black => "black",
-        };
-
-        f.write_str(name)
-    }
-}
-
 impl ByteOrPercentage {
     pub fn number(b: u8) -> Self {
         Self::Byte(b)
@@ -122,6 +121,14 @@ impl ByteOrPercentage {
     pub fn percentage(p: f32) -> Self {
         Self::Percentage(force_valid_percentage(p))
     }
+
+    /// The value of the channel, from 0 to 255
+    pub fn to_byte_value(&self) -> f64 {
+        match self {
+            ByteOrPercentage::Byte(b) => *b as f64,
+            ByteOrPercentage::Percentage(p) => *p as f64 * 2.55,
+        }
+    }
 }
 
 impl Display for ByteOrPercentage {
@@ -148,6 +155,23 @@ impl Rgb {
 
         self
     }
+
+    pub fn red(&self) -> ByteOrPercentage {
+        self.red
+    }
+
+    pub fn green(&self) -> ByteOrPercentage {
+        self.green
+    }
+
+    pub fn blue(&self) -> ByteOrPercentage {
+        self.blue
+    }
+
+    /// The alpha channel as a percentage, [None] when fully opaque by default
+    pub fn alpha(&self) -> Option<f32> {
+        self.alpha
+    }
 }
 
 impl Display for Rgb {
@@ -181,6 +205,16 @@ impl Display for Color {
     }
 }
 
+impl Angle {
+    pub fn to_degrees(&self) -> f32 {
+        match self {
+            Angle::Degrees(deg) => *deg,
+            Angle::Radians(rad) => rad.to_degrees(),
+            Angle::Turns(turns) => turns * 360.0,
+        }
+    }
+}
+
 impl Display for Angle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -224,6 +258,10 @@ impl Transform {
 
         self
     }
+
+    pub fn functions(&self) -> &[TransformFunction] {
+        &self.functions
+    }
 }
 
 impl Display for Styling {
@@ -237,6 +275,87 @@ impl Display for Styling {
     }
 }
 
+impl InlineStyle {
+    pub fn new<I>(declarations: I) -> Self
+    where
+        I: IntoIterator<Item = Styling>,
+    {
+        Self {
+            declarations: declarations.into_iter().collect(),
+        }
+    }
+}
+
+impl Display for InlineStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, declaration) in self.declarations.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            f.write_fmt(format_args!("{}", declaration))?;
+        }
+        Ok(())
+    }
+}
+
+impl DashArray {
+    pub fn new<I, L>(lengths: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<LengthOrPercentage>,
+    {
+        Self {
+            lengths: lengths.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Display for DashArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.lengths.is_empty() {
+            return f.write_str("none");
+        }
+        for (index, length) in self.lengths.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_fmt(format_args!("{}", length))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for TextAnchor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TextAnchor::Start => "start",
+            TextAnchor::Middle => "middle",
+            TextAnchor::End => "end",
+        })
+    }
+}
+
+impl Display for TransformAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, function) in self.0.functions.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            match function {
+                TransformFunction::Translate { x, y } => {
+                    f.write_fmt(format_args!("translate({} {})", x, y))?
+                }
+                // the attribute takes a plain number of degrees
+                TransformFunction::Rotate(angle) => f.write_fmt(format_args!(
+                    "rotate({})",
+                    LengthOrPercentage::from(angle.to_degrees())
+                ))?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Display for CSSRules {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (selector, rules) in &self.rules {
@@ -306,7 +425,7 @@ fn transformations() {
             y: LengthOrPercentage::HALF
         })
         .to_string(),
-        "transform: translate( 1cm 50% )".to_owned()
+        "transform: translate( 1cm, 50% )".to_owned()
     );
 
     assert_eq!(
@@ -321,6 +440,56 @@ fn transformations() {
         })
         .and_then(TransformFunction::Rotate(Angle::Degrees(-60.0)))
         .to_string(),
-        "transform: translate( 1cm 50% ) rotate( -60deg )".to_owned()
+        "transform: translate( 1cm, 50% ) rotate( -60deg )".to_owned()
     );
 }
+
+#[test]
+fn presentation_attributes() {
+    use crate::{Document, Group};
+
+    let svg = crate::svg!(
+        100,
+        100,
+        [crate::group!(
+            class: "legend",
+            font_size: "12px",
+            transform: Transform::new(TransformFunction::Translate {
+                x: LengthOrPercentage::from(10),
+                y: LengthOrPercentage::from(20)
+            })
+            .and_then(TransformFunction::Rotate(Angle::Degrees(-45.0))),
+            [
+                crate::rect!(
+                    (0, 0),
+                    (10, 10),
+                    fill: ColorName::Red,
+                    stroke_width: 1.5,
+                    dasharray: [4, 2],
+                    opacity: 2.0
+                ),
+                crate::text!(
+                    text: "Red",
+                    at: (15, 10),
+                    id: "red-label",
+                    text_anchor: TextAnchor::Middle,
+                    style: [
+                        Styling::Fill(Color::Hex(0x333333)),
+                        Styling::Raw("font-weight: bold".to_owned())
+                    ]
+                )
+            ]
+        )]
+    )
+    .to_string();
+
+    assert!(svg.contains(
+        r#"<g class="legend" font-size="12px" transform="translate(10 20) rotate(-45)">"#
+    ));
+    assert!(svg.contains(
+        r#"<rect x="0" y="0" width="10" height="10" fill="red" stroke-width="1.5" stroke-dasharray="4 2" opacity="1"/>"#
+    ));
+    assert!(svg.contains(
+        r##"<text id="red-label" x="15" y="10" text-anchor="middle" style="fill: #333333; font-weight: bold">"##
+    ));
+}
@@ -0,0 +1,104 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+use super::view_box;
+
+/// Template of elements that is not rendered itself, but instantiated by [Use](super::Use) elements
+#[xml_element("symbol")]
+#[derive(Default)]
+pub struct Symbol {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr(rename = "viewBox")]
+    view_box: Option<String>,
+
+    #[sxs_type_attr]
+    width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    height: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
+    #[sxs_type_multi_element]
+    items: Vec<XMLElement>,
+}
+
+global_attributes!(Symbol);
+
+presentation_attributes!(Symbol);
+
+has_children!(Symbol);
+
+impl Symbol {
+    pub fn new<D>(id: D) -> Self
+    where
+        D: Display,
+    {
+        Self::default().with_id(id)
+    }
+
+    /// Reference to the symbol, for a [Use](super::Use) element
+    pub fn url(&self) -> Url {
+        Url::new(self.id.as_deref().unwrap_or_default())
+    }
+
+    /// The coordinates of the contents that are mapped onto the size of each instance
+    pub fn view_box(mut self, min_x: f64, min_y: f64, width: f64, height: f64) -> Self {
+        self.view_box = Some(view_box(min_x, min_y, width, height));
+
+        self
+    }
+
+    /// Default size of the instances, which [Use](super::Use) elements may override
+    pub fn size(mut self, width: LengthOrPercentage, height: LengthOrPercentage) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+
+        self
+    }
+}
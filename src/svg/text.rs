@@ -3,7 +3,9 @@ use std::fmt::Display;
 use simple_xml_serialize::XMLElement;
 use simple_xml_serialize_macro::xml_element;
 
-use crate::LengthOrPercentage;
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
 
 #[xml_element("text")]
 #[derive(Default)]
@@ -32,12 +34,47 @@ pub struct Text {
     #[sxs_type_attr]
     rotate: Option<u16>,
 
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+
     #[sxs_type_text]
     text: String,
 }
 
 global_attributes!(Text);
 
+presentation_attributes!(Text);
+
 impl Text {
     pub fn new<D>(text: D) -> Self
     where
@@ -49,6 +86,16 @@ impl Text {
         }
     }
 
+    /// Replace the text content
+    pub fn text<D>(mut self, text: D) -> Self
+    where
+        D: Display,
+    {
+        self.text = format!("{}", text);
+
+        self
+    }
+
     /// The coordinates of the starting point of the text baseline
     pub fn at(mut self, x: LengthOrPercentage, y: LengthOrPercentage) -> Self {
         self.x = Some(x);
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use simple_xml_serialize::XMLElement;
+use simple_xml_serialize_macro::xml_element;
+
+use crate::{
+    DashArray, InlineStyle, LengthOrPercentage, Paint, TextAnchor, TransformAttribute, Url,
+};
+
+/// Instance of a [Symbol](super::Symbol), or a copy of any other element with an id
+///
+/// # Example
+/// ```rust
+/// # use d3rs::{Document, Group, Symbol, Use};
+///
+/// let mut cross = Symbol::new("cross").view_box(-1.0, -1.0, 2.0, 2.0);
+/// cross.add(d3rs::line!((-1, -1), (1, 1)));
+/// cross.add(d3rs::line!((-1, 1), (1, -1)));
+///
+/// let mut points = d3rs::group!(class: "points");
+/// for (x, y) in [(10, 10), (20, 15), (30, 5)] {
+///     points.add(Use::new(cross.url()).at(x.into(), y.into()).size(4.into(), 4.into()));
+/// }
+/// let svg = d3rs::svg!(40, 20, [cross, points]).to_string();
+///
+/// assert_eq!(svg.matches("<line").count(), 2);
+/// assert!(svg.contains(r##"<use href="#cross" x="20" y="15" width="4" height="4"/>"##));
+/// ```
+#[xml_element("use")]
+#[derive(Default)]
+pub struct Use {
+    #[sxs_type_attr]
+    id: Option<String>,
+
+    #[sxs_type_attr]
+    class: Option<String>,
+
+    #[sxs_type_attr(rename = "data-meta")]
+    meta: Option<String>,
+
+    #[sxs_type_attr]
+    href: String,
+
+    #[sxs_type_attr]
+    x: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    y: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    height: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr]
+    fill: Option<Paint>,
+
+    #[sxs_type_attr]
+    stroke: Option<Paint>,
+
+    #[sxs_type_attr(rename = "stroke-width")]
+    stroke_width: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "stroke-dasharray")]
+    dasharray: Option<DashArray>,
+
+    #[sxs_type_attr]
+    opacity: Option<f64>,
+
+    #[sxs_type_attr(rename = "font-size")]
+    font_size: Option<LengthOrPercentage>,
+
+    #[sxs_type_attr(rename = "text-anchor")]
+    text_anchor: Option<TextAnchor>,
+
+    #[sxs_type_attr]
+    transform: Option<TransformAttribute>,
+
+    #[sxs_type_attr(rename = "clip-path")]
+    clip_path: Option<Url>,
+
+    #[sxs_type_attr]
+    mask: Option<Url>,
+
+    #[sxs_type_attr]
+    style: Option<InlineStyle>,
+}
+
+global_attributes!(Use);
+
+presentation_attributes!(Use);
+
+impl Use {
+    pub fn new(target: Url) -> Self {
+        Self {
+            href: format!("#{}", target.id()),
+            ..Self::default()
+        }
+    }
+
+    /// Place the instance at `(x, y)`; for a symbol this is the top left corner of its view box
+    pub fn at(mut self, x: LengthOrPercentage, y: LengthOrPercentage) -> Self {
+        self.x = Some(x);
+        self.y = Some(y);
+
+        self
+    }
+
+    /// The size of an instance of a symbol
+    pub fn size(mut self, width: LengthOrPercentage, height: LengthOrPercentage) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+
+        self
+    }
+}
@@ -0,0 +1,626 @@
+//! Formatting and parsing of [Timestamp]s with strftime-like specifiers, as in d3-time-format
+//!
+//! All calendar fields are those in UTC, and the names of days and months are in English.
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::{
+    timestamp::{days_from_civil, is_leap_year},
+    Timestamp, MILLIS_PER_DAY, MILLIS_PER_MINUTE, MILLIS_PER_SECOND,
+};
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Formatter and parser of timestamps, following a specifier like `"%Y-%m-%d"`
+///
+/// The directives are
+/// - `%a` and `%A`: abbreviated and full weekday name
+/// - `%b` and `%B`: abbreviated and full month name
+/// - `%c`, `%x` and `%X`: date and time as `%x, %X`, date as `%-m/%-d/%Y`, time as `%-I:%M:%S %p`
+/// - `%d` and `%e`: zero and space padded day of the month
+/// - `%f` and `%L`: microseconds and milliseconds
+/// - `%g`, `%G` and `%V`: ISO 8601 week-based year without and with century, and ISO week number
+/// - `%H` and `%I`: hour in the 24-hour and in the 12-hour clock
+/// - `%j`: day of the year
+/// - `%m`, `%M` and `%S`: month, minute and second
+/// - `%p`: AM or PM
+/// - `%q`: quarter of the year
+/// - `%Q` and `%s`: milliseconds and seconds since the Unix epoch
+/// - `%u` and `%w`: Monday based (1 to 7) and Sunday based (0 to 6) weekday
+/// - `%U` and `%W`: Sunday and Monday based week number of the year
+/// - `%y` and `%Y`: year without and with century
+/// - `%Z`: time zone offset, which is always `+0000`
+/// - `%%`: a literal percent sign
+///
+/// Numbers are zero padded, except for `%e`; a `-`, `_` or `0` after the `%` pads
+/// with nothing, spaces or zeros instead. Parsing does not support the week based directives.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::{TimeFormat, Timestamp};
+///
+/// let date = TimeFormat::new("%b %-d, %Y");
+/// let timestamp = Timestamp::from_ymd(2021, 3, 4).unwrap();
+///
+/// assert_eq!(date.format(timestamp), "Mar 4, 2021");
+/// assert_eq!(date.parse("mar 4, 2021").unwrap(), timestamp);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeFormat {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Part {
+    Literal(char),
+    Directive(char, Option<char>),
+}
+
+#[derive(Debug)]
+pub struct ParseTimeError {
+    explain: String,
+}
+
+/// Fields read while parsing, before they are combined into a timestamp
+#[derive(Debug, Default)]
+struct ParsedFields {
+    year: Option<i64>,
+    month: Option<i64>,
+    quarter: Option<i64>,
+    day: Option<i64>,
+    day_of_year: Option<i64>,
+    hour: i64,
+    pm: Option<bool>,
+    minute: i64,
+    second: i64,
+    millisecond: i64,
+    offset_minutes: i64,
+    since_epoch: Option<i64>,
+}
+
+impl TimeFormat {
+    pub fn new(specifier: &str) -> Self {
+        let mut parts = Vec::new();
+        push_parts(&mut parts, specifier);
+
+        Self { parts }
+    }
+
+    /// The timestamp formatted according to the specifier
+    pub fn format(&self, timestamp: Timestamp) -> String {
+        let mut text = String::new();
+
+        for part in &self.parts {
+            match part {
+                Part::Literal(c) => text.push(*c),
+                Part::Directive(directive, pad) => {
+                    format_directive(&mut text, timestamp, *directive, *pad)
+                }
+            }
+        }
+
+        text
+    }
+
+    /// The timestamp in the text, which must match the specifier as a whole
+    ///
+    /// Fields that are absent default to midnight of January 1st, 1900.
+    pub fn parse(&self, text: &str) -> Result<Timestamp, ParseTimeError> {
+        let mut fields = ParsedFields::default();
+        let mut position = 0;
+
+        for part in &self.parts {
+            position = match part {
+                Part::Literal(c) if text[position..].starts_with(*c) => position + c.len_utf8(),
+                Part::Literal(c) => {
+                    return Err(ParseTimeError::new(text, &format!("expected '{}'", c)))
+                }
+                Part::Directive(directive, _) => {
+                    parse_directive(text, position, *directive, &mut fields)?
+                }
+            };
+        }
+        if position < text.len() {
+            return Err(ParseTimeError::new(
+                text,
+                &format!("unexpected '{}'", &text[position..]),
+            ));
+        }
+
+        fields.timestamp(text)
+    }
+
+    /// Closure for the `tick_label` of [horizontal_axis](crate::horizontal_axis) and
+    /// [vertical_axis](crate::vertical_axis), that labels every tick with its formatted timestamp
+    pub fn tick_label<D>(&self) -> impl Fn(Timestamp, D) -> Option<String> + '_ {
+        move |timestamp, _| Some(self.format(timestamp))
+    }
+}
+
+/// Formatter that picks the format by the calendar boundary a timestamp is on,
+/// like the default tick format of d3's time scale
+///
+/// Timestamps show the year at the start of a year, the month at the start of a month,
+/// the day at the start of a day, and so on down to the milliseconds.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::{MultiScaleFormat, Timestamp};
+///
+/// let format = MultiScaleFormat::default();
+///
+/// assert_eq!(format.format(Timestamp::from_ymd(2021, 1, 1).unwrap()), "2021");
+/// assert_eq!(format.format(Timestamp::from_ymd(2021, 3, 1).unwrap()), "March");
+/// assert_eq!(format.format(Timestamp::from_ymd(2021, 3, 7).unwrap()), "Mar 07");
+/// assert_eq!(format.format(Timestamp::from_ymd(2021, 3, 8).unwrap()), "Mon 08");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiScaleFormat {
+    millisecond: TimeFormat,
+    second: TimeFormat,
+    minute: TimeFormat,
+    hour: TimeFormat,
+    day: TimeFormat,
+    week: TimeFormat,
+    month: TimeFormat,
+    year: TimeFormat,
+}
+
+impl Default for MultiScaleFormat {
+    fn default() -> Self {
+        Self {
+            millisecond: TimeFormat::new(".%L"),
+            second: TimeFormat::new(":%S"),
+            minute: TimeFormat::new("%I:%M"),
+            hour: TimeFormat::new("%I %p"),
+            day: TimeFormat::new("%a %d"),
+            week: TimeFormat::new("%b %d"),
+            month: TimeFormat::new("%B"),
+            year: TimeFormat::new("%Y"),
+        }
+    }
+}
+
+impl MultiScaleFormat {
+    pub fn format(&self, timestamp: Timestamp) -> String {
+        let format = if timestamp.millisecond() > 0 {
+            &self.millisecond
+        } else if timestamp.second() > 0 {
+            &self.second
+        } else if timestamp.minute() > 0 {
+            &self.minute
+        } else if timestamp.hour() > 0 {
+            &self.hour
+        } else if timestamp.day() > 1 {
+            if timestamp.weekday() > 0 {
+                &self.day
+            } else {
+                &self.week
+            }
+        } else if timestamp.month() > 1 {
+            &self.month
+        } else {
+            &self.year
+        };
+
+        format.format(timestamp)
+    }
+
+    /// Closure for the `tick_label` of [horizontal_axis](crate::horizontal_axis) and
+    /// [vertical_axis](crate::vertical_axis)
+    pub fn tick_label<D>(&self) -> impl Fn(Timestamp, D) -> Option<String> + '_ {
+        move |timestamp, _| Some(self.format(timestamp))
+    }
+}
+
+impl ParseTimeError {
+    fn new(text: &str, reason: &str) -> Self {
+        Self {
+            explain: format!("'{}': {}", text, reason),
+        }
+    }
+}
+
+impl Error for ParseTimeError {}
+
+impl Display for ParseTimeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid time: {}", self.explain)
+    }
+}
+
+impl ParsedFields {
+    fn timestamp(&self, text: &str) -> Result<Timestamp, ParseTimeError> {
+        if let Some(millis) = self.since_epoch {
+            return millis
+                .checked_add(self.millisecond)
+                .map(Timestamp::from_millis)
+                .ok_or_else(|| ParseTimeError::new(text, "is out of range"));
+        }
+
+        let year = self.year.unwrap_or(1900) as i32;
+        let month = self
+            .month
+            .or_else(|| self.quarter.map(|quarter| quarter * 3 - 2))
+            .unwrap_or(1);
+        let hour = match self.pm {
+            Some(pm) => self.hour % 12 + if pm { 12 } else { 0 },
+            None => self.hour,
+        };
+        let invalid = || ParseTimeError::new(text, "is not a valid date and time");
+
+        let start_of_day = match self.day_of_year {
+            Some(day_of_year)
+                if (1..=if is_leap_year(year) { 366 } else { 365 }).contains(&day_of_year) =>
+            {
+                Timestamp::from_millis(
+                    (days_from_civil(year, 1, 1) + day_of_year - 1) * MILLIS_PER_DAY,
+                )
+            }
+            Some(_) => return Err(invalid()),
+            None => {
+                let field = |value: i64| u32::try_from(value).map_err(|_| invalid());
+                Timestamp::from_ymd(year, field(month)?, field(self.day.unwrap_or(1))?)
+                    .ok_or_else(invalid)?
+            }
+        };
+        let time_of_day = Timestamp::from_ymd_hms(
+            1970,
+            1,
+            1,
+            u32::try_from(hour).map_err(|_| invalid())?,
+            u32::try_from(self.minute).map_err(|_| invalid())?,
+            u32::try_from(self.second).map_err(|_| invalid())?,
+        )
+        .ok_or_else(invalid)?;
+
+        Ok(start_of_day.add_millis(
+            time_of_day.millis() + self.millisecond - self.offset_minutes * MILLIS_PER_MINUTE,
+        ))
+    }
+}
+
+fn push_parts(parts: &mut Vec<Part>, specifier: &str) {
+    let mut chars = specifier.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            parts.push(Part::Literal(c));
+            continue;
+        }
+
+        let pad = chars.next_if(|c| matches!(c, '-' | '_' | '0'));
+        match chars.next() {
+            Some('c') => push_parts(parts, "%x, %X"),
+            Some('x') => push_parts(parts, "%-m/%-d/%Y"),
+            Some('X') => push_parts(parts, "%-I:%M:%S %p"),
+            Some(directive) if "aAbBdefgGHIjLmMpqQsSuUVwWyYZ".contains(directive) => {
+                parts.push(Part::Directive(directive, pad))
+            }
+            // unknown directives, like `%%`, stand for themselves
+            Some(other) => parts.push(Part::Literal(other)),
+            None => parts.push(Part::Literal('%')),
+        }
+    }
+}
+
+fn format_directive(text: &mut String, timestamp: Timestamp, directive: char, pad: Option<char>) {
+    let fill = match pad.unwrap_or(if directive == 'e' { '_' } else { '0' }) {
+        '-' => "",
+        '_' => " ",
+        _ => "0",
+    };
+    let padded = |value: i64, width: usize| {
+        let digits = value.abs().to_string();
+
+        format!(
+            "{}{}{}",
+            if value < 0 { "-" } else { "" },
+            fill.repeat(width.saturating_sub(digits.len())),
+            digits
+        )
+    };
+    let weekday = timestamp.weekday() as i64;
+    let day_of_year = timestamp.day_of_year() as i64;
+
+    match directive {
+        'a' => text.push_str(&WEEKDAYS[weekday as usize][..3]),
+        'A' => text.push_str(WEEKDAYS[weekday as usize]),
+        'b' => text.push_str(&MONTHS[timestamp.month() as usize - 1][..3]),
+        'B' => text.push_str(MONTHS[timestamp.month() as usize - 1]),
+        'd' | 'e' => text.push_str(&padded(timestamp.day() as i64, 2)),
+        'f' => text.push_str(&padded(timestamp.millisecond() as i64 * 1000, 6)),
+        'g' => text.push_str(&padded(iso_week(timestamp).0.rem_euclid(100), 2)),
+        'G' => text.push_str(&padded(iso_week(timestamp).0 % 10_000, 4)),
+        'H' => text.push_str(&padded(timestamp.hour() as i64, 2)),
+        'I' => text.push_str(&padded((timestamp.hour() as i64 + 11) % 12 + 1, 2)),
+        'j' => text.push_str(&padded(day_of_year, 3)),
+        'L' => text.push_str(&padded(timestamp.millisecond() as i64, 3)),
+        'm' => text.push_str(&padded(timestamp.month() as i64, 2)),
+        'M' => text.push_str(&padded(timestamp.minute() as i64, 2)),
+        'p' => text.push_str(if timestamp.hour() < 12 { "AM" } else { "PM" }),
+        'q' => text.push_str(&padded((timestamp.month() as i64 + 2) / 3, 1)),
+        'Q' => text.push_str(&timestamp.millis().to_string()),
+        's' => text.push_str(&timestamp.seconds().to_string()),
+        'S' => text.push_str(&padded(timestamp.second() as i64, 2)),
+        'u' => text.push_str(&padded((weekday + 6) % 7 + 1, 1)),
+        'U' => text.push_str(&padded((day_of_year + 6 - weekday) / 7, 2)),
+        'V' => text.push_str(&padded(iso_week(timestamp).1, 2)),
+        'w' => text.push_str(&padded(weekday, 1)),
+        'W' => text.push_str(&padded((day_of_year + 6 - (weekday + 6) % 7) / 7, 2)),
+        'y' => text.push_str(&padded((timestamp.year() as i64).rem_euclid(100), 2)),
+        'Y' => text.push_str(&padded(timestamp.year() as i64 % 10_000, 4)),
+        'Z' => text.push_str("+0000"),
+        _ => text.push(directive),
+    }
+}
+
+/// The ISO 8601 week-based year and week number, in which weeks start on Monday and
+/// the first week of a year is the one with its Thursday
+fn iso_week(timestamp: Timestamp) -> (i64, i64) {
+    let year = timestamp.year() as i64;
+    let iso_weekday = (timestamp.weekday() as i64 + 6) % 7 + 1;
+    let week = (timestamp.day_of_year() as i64 - iso_weekday + 10) / 7;
+    let weeks_in_year = |year: i64| {
+        let january_first = (days_from_civil(year as i32, 1, 1) + 4).rem_euclid(7);
+        if january_first == 4 || (january_first == 3 && is_leap_year(year as i32)) {
+            53
+        } else {
+            52
+        }
+    };
+
+    if week < 1 {
+        (year - 1, weeks_in_year(year - 1))
+    } else if week > weeks_in_year(year) {
+        (year + 1, 1)
+    } else {
+        (year, week)
+    }
+}
+
+fn parse_directive(
+    text: &str,
+    position: usize,
+    directive: char,
+    fields: &mut ParsedFields,
+) -> Result<usize, ParseTimeError> {
+    let rest = &text[position..];
+    let expected = |what: &str| ParseTimeError::new(text, &format!("expected {}", what));
+    let number = |max_length: usize, what: &str| {
+        parse_number(rest, max_length)
+            .map(|(value, length)| (value, position + length))
+            .ok_or_else(|| expected(what))
+    };
+    let name = |names: &[&str], abbreviated: bool, what: &str| {
+        names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (index, if abbreviated { &name[..3] } else { name }))
+            .find(|(_, name)| {
+                rest.get(..name.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(name))
+            })
+            .map(|(index, name)| (index as i64, position + name.len()))
+            .ok_or_else(|| expected(what))
+    };
+
+    let (value, position) = match directive {
+        'a' => name(&WEEKDAYS, true, "a weekday")?,
+        'A' => name(&WEEKDAYS, false, "a weekday")?,
+        'b' => name(&MONTHS, true, "a month")?,
+        'B' => name(&MONTHS, false, "a month")?,
+        'd' | 'e' => number(2, "a day")?,
+        'f' => number(6, "microseconds")?,
+        'H' | 'I' => number(2, "an hour")?,
+        'j' => number(3, "a day of the year")?,
+        'L' => number(3, "milliseconds")?,
+        'm' => number(2, "a month")?,
+        'M' => number(2, "minutes")?,
+        'p' => name(&["AM", "PM"], false, "AM or PM")?,
+        'q' => number(1, "a quarter")?,
+        'Q' | 's' => number(usize::MAX, "a number")?,
+        'S' => number(2, "seconds")?,
+        'u' | 'w' => number(1, "a weekday")?,
+        'y' => number(2, "a year")?,
+        'Y' => number(4, "a year")?,
+        'Z' => parse_zone(rest)
+            .map(|(offset, length)| (offset, position + length))
+            .ok_or_else(|| expected("a time zone offset"))?,
+        _ => {
+            return Err(ParseTimeError::new(
+                text,
+                &format!("'%{}' cannot be parsed", directive),
+            ))
+        }
+    };
+
+    match directive {
+        'b' | 'B' => fields.month = Some(value + 1),
+        'd' | 'e' => fields.day = Some(value),
+        'f' => fields.millisecond = value / 1000,
+        'H' | 'I' => fields.hour = value,
+        'j' => fields.day_of_year = Some(value),
+        'L' => fields.millisecond = value,
+        'm' => fields.month = Some(value),
+        'M' => fields.minute = value,
+        'p' => fields.pm = Some(value == 1),
+        'q' => fields.quarter = Some(value),
+        'Q' => fields.since_epoch = Some(value),
+        's' => {
+            fields.since_epoch = Some(
+                value
+                    .checked_mul(MILLIS_PER_SECOND)
+                    .ok_or_else(|| ParseTimeError::new(text, "is out of range"))?,
+            )
+        }
+        'S' => fields.second = value,
+        'y' => fields.year = Some(value + if value > 68 { 1900 } else { 2000 }),
+        'Y' => fields.year = Some(value),
+        'Z' => fields.offset_minutes = value,
+        // weekdays follow from the date
+        _ => {}
+    }
+
+    Ok(position)
+}
+
+/// A number of at most the given length, which includes leading whitespace and a sign
+fn parse_number(text: &str, max_length: usize) -> Option<(i64, usize)> {
+    let limit = text
+        .char_indices()
+        .nth(max_length)
+        .map_or(text.len(), |(index, _)| index);
+    let candidate = &text[..limit];
+
+    let start = candidate.len() - candidate.trim_start().len();
+    let digits_start = start + candidate[start..].strip_prefix(['+', '-']).map_or(0, |_| 1);
+    let end = candidate[digits_start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(candidate.len(), |index| digits_start + index);
+
+    if end > digits_start {
+        candidate[start..end].parse().ok().map(|value| (value, end))
+    } else {
+        None
+    }
+}
+
+/// The offset in minutes of `Z`, `±hh`, `±hhmm` or `±hh:mm`
+fn parse_zone(text: &str) -> Option<(i64, usize)> {
+    if text.starts_with('Z') {
+        return Some((0, 1));
+    }
+
+    let sign = match text.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let two_digits = |from: usize| {
+        text.get(from..from + 2)
+            .filter(|digits| digits.chars().all(|c| c.is_ascii_digit()))
+            .and_then(|digits| digits.parse::<i64>().ok())
+    };
+    let hours = two_digits(1)?;
+    let (minutes, length) = match (two_digits(3), text[3..].starts_with(':')) {
+        (Some(minutes), _) => (minutes, 5),
+        (None, true) => (two_digits(4)?, 6),
+        (None, false) => (0, 3),
+    };
+
+    Some((sign * (hours * 60 + minutes), length))
+}
+
+#[test]
+fn format_timestamps() {
+    let timestamp = Timestamp::from_ymd_hms(2021, 1, 3, 14, 5, 9)
+        .unwrap()
+        .add_millis(42);
+    let format = |specifier: &str| TimeFormat::new(specifier).format(timestamp);
+
+    assert_eq!(format("%Y-%m-%d"), "2021-01-03");
+    assert_eq!(format("%b %d"), "Jan 03");
+    assert_eq!(format("%H:%M"), "14:05");
+    assert_eq!(
+        format("%A %-d %B %Y, %I:%M %p"),
+        "Sunday 3 January 2021, 02:05 PM"
+    );
+    assert_eq!(format("%c"), "1/3/2021, 2:05:09 PM");
+    assert_eq!(format("%e|%_m|%0e"), " 3| 1|03");
+    assert_eq!(format("%S.%L %f %Z"), "09.042 042000 +0000");
+    assert_eq!(format("%j %q %u %w %%"), "003 1 7 0 %");
+    // the 3rd of January 2021 is in the last ISO week of 2020
+    assert_eq!(format("%G-W%V %g %U %W"), "2020-W53 20 01 00");
+    assert_eq!(format("%s %Q"), "1609682709 1609682709042");
+}
+
+#[test]
+fn parse_timestamps() {
+    let parse = |specifier: &str, text: &str| TimeFormat::new(specifier).parse(text);
+
+    assert_eq!(
+        parse("%Y-%m-%d", "2021-03-04").unwrap(),
+        Timestamp::from_ymd(2021, 3, 4).unwrap()
+    );
+    assert_eq!(
+        parse("%d/%m/%y %I:%M %p", "4/3/21 1:30 pm").unwrap(),
+        Timestamp::from_ymd_hms(2021, 3, 4, 13, 30, 0).unwrap()
+    );
+    assert_eq!(
+        parse("%Y-%m-%dT%H:%M:%S.%L%Z", "2021-03-04T01:00:00.250+02:00").unwrap(),
+        Timestamp::from_ymd_hms(2021, 3, 3, 23, 0, 0)
+            .unwrap()
+            .add_millis(250)
+    );
+    assert_eq!(
+        parse("%B %Y", "MARCH 2021").unwrap(),
+        Timestamp::from_ymd(2021, 3, 1).unwrap()
+    );
+    assert_eq!(
+        parse("%Y %j", "2020 366").unwrap(),
+        Timestamp::from_ymd(2020, 12, 31).unwrap()
+    );
+    assert_eq!(parse("%s", "-1").unwrap(), Timestamp::from_seconds(-1));
+    assert_eq!(
+        parse("%H:%M", "07:45").unwrap(),
+        Timestamp::from_ymd_hms(1900, 1, 1, 7, 45, 0).unwrap()
+    );
+
+    assert!(parse("%Y-%m-%d", "2021-02-29").is_err());
+    assert!(parse("%Y-%m-%d", "2021-03-04 extra").is_err());
+    assert!(parse("%Y-%m", "2021/03").is_err());
+    assert!(parse("%U", "01").is_err());
+
+    // numbers of seconds or milliseconds beyond the range of a timestamp
+    assert!(parse("%s", "99999999999999999").is_err());
+    assert!(parse("%Q.%L", "9223372036854775807.999").is_err());
+}
+
+#[test]
+fn multi_scale_tick_labels() {
+    use crate::{IterableScale, Time};
+
+    let time = Time::try_new(
+        Timestamp::from_ymd(2020, 10, 1).unwrap(),
+        Timestamp::from_ymd(2021, 3, 1).unwrap(),
+        300,
+    )
+    .unwrap();
+    let format = MultiScaleFormat::default();
+    let labels: Vec<String> = time
+        .intervals(25 * MILLIS_PER_DAY)
+        .filter_map(|(timestamp, coordinate)| format.tick_label()(timestamp, coordinate))
+        .collect();
+
+    assert_eq!(
+        labels,
+        vec!["October", "November", "December", "2021", "February", "March"]
+    );
+}
@@ -0,0 +1,407 @@
+//! Calendar intervals, to floor, ceil and step [Timestamp]s on calendar boundaries
+//!
+//! Boundaries are those in UTC, unless an interval has a fixed offset from UTC
+//! for the local time, like `+01:00` for Central European Time.
+
+use crate::{
+    timestamp::{civil_from_days, days_from_civil, days_in_month},
+    Timestamp, MILLIS_PER_DAY, MILLIS_PER_HOUR, MILLIS_PER_MINUTE, MILLIS_PER_SECOND,
+    MILLIS_PER_WEEK,
+};
+
+/// Approximate durations of the units that vary in length
+const MILLIS_PER_MONTH: i64 = 30 * MILLIS_PER_DAY;
+const MILLIS_PER_YEAR: i64 = 365 * MILLIS_PER_DAY;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Unit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// Weeks starting on the weekday, from 0 (Sunday) to 6 (Saturday)
+    Week(u32),
+    Month,
+    Year,
+}
+
+/// Interval of a calendar unit, like a day or a month, or of a multiple of it
+///
+/// Multiples restart at the start of the enclosing unit, e.g. every other day
+/// is on the odd days of each month and every quarter starts in January, April,
+/// July and October.
+///
+/// # Example
+/// ```rust
+/// # use d3rs::{TimeInterval, Timestamp};
+///
+/// let monday = TimeInterval::week(1);
+/// let timestamp = Timestamp::from_ymd_hms(2021, 3, 4, 5, 6, 7).unwrap();
+///
+/// assert_eq!(monday.floor(timestamp), Timestamp::from_ymd(2021, 3, 1).unwrap());
+/// assert_eq!(monday.ceil(timestamp), Timestamp::from_ymd(2021, 3, 8).unwrap());
+///
+/// let quarters: Vec<u32> = TimeInterval::month()
+///     .every(3)
+///     .range(timestamp, Timestamp::from_ymd(2022, 1, 1).unwrap())
+///     .map(|quarter| quarter.month())
+///     .collect();
+/// assert_eq!(quarters, vec![4, 7, 10]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeInterval {
+    unit: Unit,
+    step: i64,
+    /// Offset of the local time from UTC, in milliseconds
+    utc_offset: i64,
+}
+
+/// Boundaries of an interval from a start up to, but excluding, a stop
+#[derive(Debug, Clone)]
+pub struct TimeIntervalIter {
+    interval: TimeInterval,
+    next: Timestamp,
+    stop: Timestamp,
+}
+
+impl TimeInterval {
+    const fn of(unit: Unit) -> Self {
+        Self {
+            unit,
+            step: 1,
+            utc_offset: 0,
+        }
+    }
+
+    pub const fn millisecond() -> Self {
+        Self::of(Unit::Millisecond)
+    }
+
+    pub const fn second() -> Self {
+        Self::of(Unit::Second)
+    }
+
+    pub const fn minute() -> Self {
+        Self::of(Unit::Minute)
+    }
+
+    pub const fn hour() -> Self {
+        Self::of(Unit::Hour)
+    }
+
+    pub const fn day() -> Self {
+        Self::of(Unit::Day)
+    }
+
+    /// Weeks starting on a weekday, from 0 (Sunday) to 6 (Saturday); others start on Sunday
+    pub const fn week(weekday: u32) -> Self {
+        Self::of(Unit::Week(if weekday < 7 { weekday } else { 0 }))
+    }
+
+    pub const fn month() -> Self {
+        Self::of(Unit::Month)
+    }
+
+    pub const fn year() -> Self {
+        Self::of(Unit::Year)
+    }
+
+    /// Every n-th boundary of the unit; a step below 1 is taken as 1
+    pub const fn every(mut self, step: i64) -> Self {
+        self.step = if step > 0 { step } else { 1 };
+
+        self
+    }
+
+    /// Boundaries in local time, the given number of minutes ahead of UTC
+    ///
+    /// Offsets of a day or more are ignored.
+    pub const fn utc_offset(mut self, minutes: i64) -> Self {
+        self.utc_offset = if -24 * 60 < minutes && minutes < 24 * 60 {
+            minutes * MILLIS_PER_MINUTE
+        } else {
+            0
+        };
+
+        self
+    }
+
+    /// The latest boundary at or before the timestamp
+    pub fn floor(&self, timestamp: Timestamp) -> Timestamp {
+        let local = timestamp.millis() + self.utc_offset;
+        let days = local.div_euclid(MILLIS_PER_DAY);
+
+        let floored = match self.unit {
+            Unit::Millisecond | Unit::Second | Unit::Minute | Unit::Hour => {
+                let unit = self.unit_duration();
+                let enclosing = match self.unit {
+                    Unit::Millisecond => MILLIS_PER_SECOND,
+                    Unit::Second => MILLIS_PER_MINUTE,
+                    Unit::Minute => MILLIS_PER_HOUR,
+                    _ => MILLIS_PER_DAY,
+                };
+                let start = local - local.rem_euclid(unit);
+                // the value of the field, like the hour of the day
+                let field = start.rem_euclid(enclosing) / unit;
+                start - field % self.step * unit
+            }
+            Unit::Day => {
+                let (_, _, day) = civil_from_days(days);
+                (days - (day as i64 - 1) % self.step) * MILLIS_PER_DAY
+            }
+            Unit::Week(weekday) => {
+                let start = days - (days + 4 - weekday as i64).rem_euclid(7);
+                // count the weeks from the first one that starts before the epoch
+                let first = -(4 - weekday as i64).rem_euclid(7);
+                let weeks = (start - first) / 7;
+                (start - weeks.rem_euclid(self.step) * 7) * MILLIS_PER_DAY
+            }
+            Unit::Month => {
+                let (year, month, _) = civil_from_days(days);
+                let month = month as i64 - 1;
+                days_from_civil(year, (month - month % self.step) as u32 + 1, 1) * MILLIS_PER_DAY
+            }
+            Unit::Year => {
+                let (year, _, _) = civil_from_days(days);
+                let year = year as i64 - (year as i64).rem_euclid(self.step);
+                days_from_civil(year as i32, 1, 1) * MILLIS_PER_DAY
+            }
+        };
+
+        Timestamp::from_millis(floored - self.utc_offset)
+    }
+
+    /// The earliest boundary at or after the timestamp
+    pub fn ceil(&self, timestamp: Timestamp) -> Timestamp {
+        let floored = self.floor(timestamp);
+
+        if floored == timestamp {
+            timestamp
+        } else {
+            self.next(floored)
+        }
+    }
+
+    /// The timestamp moved by a number of steps of the interval, which may be negative
+    ///
+    /// The time of day is kept when moving days, weeks, months and years, and the day
+    /// of the month is limited to the length of the month, e.g. from January 31st one month
+    /// on is February 28th or 29th.
+    pub fn offset(&self, timestamp: Timestamp, steps: i64) -> Timestamp {
+        let local = timestamp.millis() + self.utc_offset;
+        let steps = steps * self.step;
+
+        let moved = match self.unit {
+            Unit::Month | Unit::Year => {
+                let (year, month, day) = civil_from_days(local.div_euclid(MILLIS_PER_DAY));
+                let months = year as i64 * 12 + month as i64 - 1
+                    + if self.unit == Unit::Month {
+                        steps
+                    } else {
+                        steps * 12
+                    };
+                let (year, month) = (
+                    months.div_euclid(12) as i32,
+                    months.rem_euclid(12) as u32 + 1,
+                );
+
+                days_from_civil(year, month, day.min(days_in_month(year, month))) * MILLIS_PER_DAY
+                    + local.rem_euclid(MILLIS_PER_DAY)
+            }
+            _ => local + steps * self.unit_duration(),
+        };
+
+        Timestamp::from_millis(moved - self.utc_offset)
+    }
+
+    /// The boundaries from the start up to, but excluding, the stop
+    pub fn range(&self, start: Timestamp, stop: Timestamp) -> TimeIntervalIter {
+        TimeIntervalIter {
+            interval: *self,
+            next: self.ceil(start),
+            stop,
+        }
+    }
+
+    /// Approximate duration of a step, in milliseconds
+    pub(crate) fn duration(&self) -> i64 {
+        self.step * self.unit_duration()
+    }
+
+    fn unit_duration(&self) -> i64 {
+        match self.unit {
+            Unit::Millisecond => 1,
+            Unit::Second => MILLIS_PER_SECOND,
+            Unit::Minute => MILLIS_PER_MINUTE,
+            Unit::Hour => MILLIS_PER_HOUR,
+            Unit::Day => MILLIS_PER_DAY,
+            Unit::Week(_) => MILLIS_PER_WEEK,
+            Unit::Month => MILLIS_PER_MONTH,
+            Unit::Year => MILLIS_PER_YEAR,
+        }
+    }
+
+    /// The boundary after the given one
+    fn next(&self, boundary: Timestamp) -> Timestamp {
+        // realign, e.g. every other day restarts at the first day of each month
+        self.floor(self.offset(boundary, 1))
+    }
+}
+
+impl Iterator for TimeIntervalIter {
+    type Item = Timestamp;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next < self.stop {
+            let current = self.next;
+            self.next = self.interval.next(current);
+
+            Some(current)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn floor_and_ceil() {
+    let timestamp = Timestamp::from_ymd_hms(2021, 3, 4, 5, 6, 7).unwrap();
+    let at = |year, month, day, hour, minute, second| {
+        Timestamp::from_ymd_hms(year, month, day, hour, minute, second).unwrap()
+    };
+
+    assert_eq!(
+        TimeInterval::second().floor(timestamp.add_millis(500)),
+        timestamp
+    );
+    assert_eq!(
+        TimeInterval::minute().floor(timestamp),
+        at(2021, 3, 4, 5, 6, 0)
+    );
+    assert_eq!(
+        TimeInterval::minute().ceil(timestamp),
+        at(2021, 3, 4, 5, 7, 0)
+    );
+    assert_eq!(
+        TimeInterval::hour().every(6).ceil(timestamp),
+        at(2021, 3, 4, 6, 0, 0)
+    );
+    // multiples of hours restart at midnight
+    assert_eq!(
+        TimeInterval::hour().every(5).floor(at(2021, 3, 4, 3, 0, 0)),
+        at(2021, 3, 4, 0, 0, 0)
+    );
+    assert_eq!(
+        TimeInterval::minute()
+            .every(7)
+            .floor(at(2021, 3, 4, 5, 59, 0)),
+        at(2021, 3, 4, 5, 56, 0)
+    );
+    let hours: Vec<u32> = TimeInterval::hour()
+        .every(5)
+        .range(at(2021, 3, 4, 14, 0, 0), at(2021, 3, 5, 6, 0, 0))
+        .map(|boundary| boundary.hour())
+        .collect();
+    assert_eq!(hours, vec![15, 20, 0, 5]);
+    assert_eq!(
+        TimeInterval::day().floor(timestamp),
+        at(2021, 3, 4, 0, 0, 0)
+    );
+    assert_eq!(
+        TimeInterval::week(0).floor(timestamp),
+        at(2021, 2, 28, 0, 0, 0)
+    );
+    assert_eq!(
+        TimeInterval::week(4).floor(timestamp),
+        at(2021, 3, 4, 0, 0, 0)
+    );
+    assert_eq!(
+        TimeInterval::month().ceil(timestamp),
+        at(2021, 4, 1, 0, 0, 0)
+    );
+    assert_eq!(
+        TimeInterval::year().floor(timestamp),
+        at(2021, 1, 1, 0, 0, 0)
+    );
+    assert_eq!(
+        TimeInterval::year().every(10).floor(timestamp),
+        at(2020, 1, 1, 0, 0, 0)
+    );
+
+    let boundary = at(2021, 1, 1, 0, 0, 0);
+    assert_eq!(TimeInterval::year().ceil(boundary), boundary);
+    assert_eq!(
+        TimeInterval::year().floor(Timestamp::from_millis(boundary.millis() - 1)),
+        at(2020, 1, 1, 0, 0, 0)
+    );
+}
+
+#[test]
+fn offset_and_range() {
+    let at = |year, month, day| Timestamp::from_ymd(year, month, day).unwrap();
+
+    assert_eq!(
+        TimeInterval::month().offset(at(2020, 1, 31), 1),
+        at(2020, 2, 29)
+    );
+    assert_eq!(
+        TimeInterval::month().offset(at(2020, 1, 31), -2),
+        at(2019, 11, 30)
+    );
+    assert_eq!(
+        TimeInterval::year().offset(at(2020, 2, 29), 1),
+        at(2021, 2, 28)
+    );
+    assert_eq!(
+        TimeInterval::week(1).every(2).offset(at(2020, 1, 1), 1),
+        at(2020, 1, 15)
+    );
+
+    let days: Vec<u32> = TimeInterval::day()
+        .every(2)
+        .range(at(2021, 1, 28), at(2021, 2, 4))
+        .map(|day| day.day())
+        .collect();
+    assert_eq!(days, vec![29, 31, 1, 3]);
+
+    let fortnights: Vec<Timestamp> = TimeInterval::week(1)
+        .every(2)
+        .range(at(2021, 3, 1), at(2021, 4, 1))
+        .collect();
+    assert_eq!(fortnights.len(), 3);
+    assert!(fortnights.iter().all(|monday| monday.weekday() == 1));
+    assert_eq!(fortnights[1] - fortnights[0], 2 * MILLIS_PER_WEEK);
+
+    assert_eq!(
+        TimeInterval::month()
+            .range(at(2021, 3, 1), at(2021, 3, 1))
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn local_offsets() {
+    // Central European Time and Eastern Standard Time
+    let cet = TimeInterval::day().utc_offset(60);
+    let est = TimeInterval::month().utc_offset(-5 * 60);
+    let timestamp = Timestamp::from_ymd_hms(2021, 3, 31, 23, 30, 0).unwrap();
+
+    assert_eq!(
+        cet.floor(timestamp),
+        Timestamp::from_ymd_hms(2021, 3, 31, 23, 0, 0).unwrap()
+    );
+    assert_eq!(
+        est.floor(timestamp),
+        Timestamp::from_ymd_hms(2021, 3, 1, 5, 0, 0).unwrap()
+    );
+    assert_eq!(
+        est.offset(est.floor(timestamp), 1),
+        Timestamp::from_ymd_hms(2021, 4, 1, 5, 0, 0).unwrap()
+    );
+    assert_eq!(
+        TimeInterval::hour().utc_offset(24 * 60),
+        TimeInterval::hour()
+    );
+}
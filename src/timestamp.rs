@@ -0,0 +1,237 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Sub,
+};
+
+pub const MILLIS_PER_SECOND: i64 = 1_000;
+pub const MILLIS_PER_MINUTE: i64 = 60 * MILLIS_PER_SECOND;
+pub const MILLIS_PER_HOUR: i64 = 60 * MILLIS_PER_MINUTE;
+pub const MILLIS_PER_DAY: i64 = 24 * MILLIS_PER_HOUR;
+pub const MILLIS_PER_WEEK: i64 = 7 * MILLIS_PER_DAY;
+
+/// Instant in time, counted in milliseconds since the Unix epoch (1970-01-01T00:00:00Z)
+///
+/// The calendar fields are those of the proleptic Gregorian calendar in UTC.
+/// Subtracting timestamps works on the milliseconds, and a duration in milliseconds
+/// can be added with [Timestamp::add_millis].
+///
+/// # Example
+/// ```rust
+/// # use d3rs::Timestamp;
+///
+/// let moon_landing = Timestamp::from_ymd_hms(1969, 7, 20, 20, 17, 40).unwrap();
+///
+/// assert_eq!(moon_landing.year(), 1969);
+/// assert_eq!(moon_landing.weekday(), 0);
+/// assert_eq!(moon_landing.to_string(), "1969-07-20T20:17:40.000Z");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    millis: i64,
+}
+
+impl Timestamp {
+    pub const EPOCH: Timestamp = Timestamp { millis: 0 };
+
+    pub fn from_millis(millis: i64) -> Self {
+        Self { millis }
+    }
+
+    pub fn from_seconds(seconds: i64) -> Self {
+        Self {
+            millis: seconds * MILLIS_PER_SECOND,
+        }
+    }
+
+    /// Midnight UTC of the given date, or [None] when the date does not exist
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        Self::from_ymd_hms(year, month, day, 0, 0, 0)
+    }
+
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Option<Self> {
+        if (1..=12).contains(&month)
+            && 1 <= day
+            && day <= days_in_month(year, month)
+            && hour < 24
+            && minute < 60
+            && second < 60
+        {
+            Some(Self {
+                millis: days_from_civil(year, month, day) * MILLIS_PER_DAY
+                    + hour as i64 * MILLIS_PER_HOUR
+                    + minute as i64 * MILLIS_PER_MINUTE
+                    + second as i64 * MILLIS_PER_SECOND,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The timestamp the given number of milliseconds later, or earlier when negative
+    pub fn add_millis(self, millis: i64) -> Self {
+        Self {
+            millis: self.millis + millis,
+        }
+    }
+
+    pub fn millis(&self) -> i64 {
+        self.millis
+    }
+
+    pub fn seconds(&self) -> i64 {
+        self.millis.div_euclid(MILLIS_PER_SECOND)
+    }
+
+    /// Days since the epoch
+    pub fn days(&self) -> i64 {
+        self.millis.div_euclid(MILLIS_PER_DAY)
+    }
+
+    pub fn year(&self) -> i32 {
+        civil_from_days(self.days()).0
+    }
+
+    /// Month of the year, from 1 (January) to 12 (December)
+    pub fn month(&self) -> u32 {
+        civil_from_days(self.days()).1
+    }
+
+    /// Day of the month, starting at 1
+    pub fn day(&self) -> u32 {
+        civil_from_days(self.days()).2
+    }
+
+    /// Day of the year, starting at 1
+    pub fn day_of_year(&self) -> u32 {
+        let year = self.year();
+        (self.days() - days_from_civil(year, 1, 1)) as u32 + 1
+    }
+
+    /// Day of the week, from 0 (Sunday) to 6 (Saturday)
+    pub fn weekday(&self) -> u32 {
+        // the epoch was on a Thursday
+        (self.days() + 4).rem_euclid(7) as u32
+    }
+
+    pub fn hour(&self) -> u32 {
+        (self.millis.rem_euclid(MILLIS_PER_DAY) / MILLIS_PER_HOUR) as u32
+    }
+
+    pub fn minute(&self) -> u32 {
+        (self.millis.rem_euclid(MILLIS_PER_HOUR) / MILLIS_PER_MINUTE) as u32
+    }
+
+    pub fn second(&self) -> u32 {
+        (self.millis.rem_euclid(MILLIS_PER_MINUTE) / MILLIS_PER_SECOND) as u32
+    }
+
+    pub fn millisecond(&self) -> u32 {
+        self.millis.rem_euclid(MILLIS_PER_SECOND) as u32
+    }
+}
+
+/// The number of milliseconds from one timestamp to another
+impl Sub for Timestamp {
+    type Output = i64;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.millis - rhs.millis
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = civil_from_days(self.days());
+        f.write_fmt(format_args!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            year,
+            month,
+            day,
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.millisecond()
+        ))
+    }
+}
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since the epoch of a date in the proleptic Gregorian calendar
+///
+/// See Howard Hinnant's [date algorithms](http://howardhinnant.github.io/date_algorithms.html)
+pub(crate) fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = year as i64 - if month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year, month and day of the given number of days since the epoch
+pub(crate) fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = (year_of_era + era * 400) as i32 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[test]
+fn calendar_fields() {
+    let leap_day = Timestamp::from_ymd_hms(2024, 2, 29, 13, 14, 15).unwrap();
+    assert_eq!(leap_day.year(), 2024);
+    assert_eq!(leap_day.month(), 2);
+    assert_eq!(leap_day.day(), 29);
+    assert_eq!(leap_day.day_of_year(), 60);
+    assert_eq!(leap_day.weekday(), 4);
+    assert_eq!(leap_day.hour(), 13);
+    assert_eq!(leap_day.minute(), 14);
+    assert_eq!(leap_day.second(), 15);
+
+    assert_eq!(Timestamp::from_ymd(2023, 2, 29), None);
+    assert_eq!(Timestamp::from_ymd(1970, 1, 1), Some(Timestamp::EPOCH));
+
+    let before_epoch = Timestamp::from_millis(-1);
+    assert_eq!(before_epoch.to_string(), "1969-12-31T23:59:59.999Z");
+}
+
+#[test]
+fn civil_round_trip() {
+    for days in -800_000..800_000 {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), days);
+    }
+}